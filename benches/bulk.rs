@@ -0,0 +1,86 @@
+/*!
+Compares the bulk slice conversions against converting one element at a time.
+
+Run with `cargo bench`.
+*/
+extern crate conv;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use conv::{ApproxFrom, RoundToNearest, ValueFrom};
+use conv::bulk::{approx_slice_by, value_slice};
+
+const LEN: usize = 1 << 16;
+const ITERS: u32 = 200;
+
+fn time<F>(mut f: F) -> Duration where F: FnMut() {
+    // Warm up first.
+    f();
+    let start = Instant::now();
+    for _ in 0..ITERS {
+        f();
+    }
+    start.elapsed() / ITERS
+}
+
+fn report(name: &str, scalar: Duration, bulk: Duration) {
+    println!("{:<24} scalar: {:>10.1?}  bulk: {:>10.1?}  speedup: {:.2}x",
+        name, scalar, bulk, scalar.as_secs_f64() / bulk.as_secs_f64());
+}
+
+fn bench_f64_to_i16() {
+    let src: Vec<f64> = (0..LEN).map(|i| (i as f64 * 0.37).sin() * 30_000.0).collect();
+    let mut dst = vec![0i16; LEN];
+
+    let scalar = time(|| {
+        for (d, &s) in dst.iter_mut().zip(black_box(&src)) {
+            *d = <i16 as ApproxFrom<f64, RoundToNearest>>::approx_from(s).unwrap();
+        }
+        black_box(&dst);
+    });
+    let bulk = time(|| {
+        approx_slice_by::<RoundToNearest, _, _>(black_box(&src), &mut dst).unwrap();
+        black_box(&dst);
+    });
+    report("f64 -> i16 (nearest)", scalar, bulk);
+}
+
+fn bench_f32_to_u8() {
+    let src: Vec<f32> = (0..LEN).map(|i| (i % 256) as f32 + 0.25).collect();
+    let mut dst = vec![0u8; LEN];
+
+    let scalar = time(|| {
+        for (d, &s) in dst.iter_mut().zip(black_box(&src)) {
+            *d = <u8 as ApproxFrom<f32, RoundToNearest>>::approx_from(s).unwrap();
+        }
+        black_box(&dst);
+    });
+    let bulk = time(|| {
+        approx_slice_by::<RoundToNearest, _, _>(black_box(&src), &mut dst).unwrap();
+        black_box(&dst);
+    });
+    report("f32 -> u8 (nearest)", scalar, bulk);
+}
+
+fn bench_i32_to_f32() {
+    let src: Vec<i32> = (0..LEN as i32).map(|i| i * 31 % 1_000_000 - 500_000).collect();
+    let mut dst = vec![0f32; LEN];
+
+    let scalar = time(|| {
+        for (d, &s) in dst.iter_mut().zip(black_box(&src)) {
+            *d = <f32 as ValueFrom<i32>>::value_from(s).unwrap();
+        }
+        black_box(&dst);
+    });
+    let bulk = time(|| {
+        value_slice(black_box(&src), &mut dst).unwrap();
+        black_box(&dst);
+    });
+    report("i32 -> f32 (value)", scalar, bulk);
+}
+
+fn main() {
+    bench_f64_to_i16();
+    bench_f32_to_u8();
+    bench_i32_to_f32();
+}
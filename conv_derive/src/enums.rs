@@ -0,0 +1,269 @@
+/*!
+Helpers shared by the derives that operate on enumerations.
+*/
+
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    Attribute, DataEnum, DeriveInput, Error, Expr, ExprGroup, ExprLit, ExprParen, ExprUnary, Fields,
+    Ident, Lit, LitStr, Result, Type, UnOp, Variant,
+};
+use syn::punctuated::Punctuated;
+use syn::token;
+
+/// The names of the primitive integer types.
+pub const INT_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize",
+    "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+/// Returns the enum data of `input`, or an error pointing at the `struct`/`union` keyword.
+pub fn enum_data<'a>(input: &'a DeriveInput, derive: &str) -> Result<&'a DataEnum> {
+    match input.data {
+        syn::Data::Enum(ref data) => Ok(data),
+        syn::Data::Struct(ref data) => Err(Error::new_spanned(data.struct_token,
+            format!("`{}` can only be derived for enums", derive))),
+        syn::Data::Union(ref data) => Err(Error::new_spanned(data.union_token,
+            format!("`{}` can only be derived for enums", derive))),
+    }
+}
+
+/// Returns the integer type given in a `#[repr(..)]` attribute, if any.
+pub fn int_repr(attrs: &[Attribute]) -> Result<Option<Ident>> {
+    let mut repr = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if INT_TYPES.iter().any(|t| ident == t) {
+                    repr = Some(ident.clone());
+                }
+            }
+            // Skip the arguments of things like `align(..)`.
+            if meta.input.peek(token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+    Ok(repr)
+}
+
+/// Parses every `#[name(Type, ...)]` attribute, returning the listed types in order.
+pub fn type_list(attrs: &[Attribute], name: &str) -> Result<Vec<Type>> {
+    let mut types = vec![];
+    for attr in attrs.iter().filter(|a| a.path().is_ident(name)) {
+        let list = attr.parse_args_with(Punctuated::<Type, syn::Token![,]>::parse_terminated)?;
+        if list.is_empty() {
+            return Err(Error::new_spanned(attr, format!("expected at least one type in `#[{}(..)]`", name)));
+        }
+        types.extend(list);
+    }
+    Ok(types)
+}
+
+/// The options given to a variant in `#[name(..)]` attributes.
+#[derive(Default)]
+pub struct VariantOptions {
+    /// Set by `skip`.
+    pub skip: bool,
+    /// Set by `alias = "..."`; may be given more than once.
+    pub aliases: Vec<LitStr>,
+    /// Set by `catch_all`.
+    pub catch_all: Option<Ident>,
+}
+
+impl VariantOptions {
+    pub fn parse(variant: &Variant, name: &str) -> Result<VariantOptions> {
+        let mut opts = VariantOptions::default();
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident(name)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    opts.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("alias") {
+                    opts.aliases.push(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("catch_all") {
+                    opts.catch_all = meta.path.get_ident().cloned();
+                    Ok(())
+                } else {
+                    Err(meta.error(format!(
+                        "unsupported `{}` option on a variant; expected `skip`, `alias = \"..\"` or `catch_all`", name)))
+                }
+            })?;
+        }
+        Ok(opts)
+    }
+}
+
+/// A unit variant of an enumeration.
+pub struct UnitVariant<'a> {
+    pub ident: &'a Ident,
+    /// Any aliases given with `#[attr(alias = "..")]`.
+    pub aliases: Vec<LitStr>,
+    /// The nearest preceding explicit discriminant, if any.
+    base: Option<&'a Expr>,
+    /// The number of variants since `base`, or since the first variant.
+    offset: usize,
+}
+
+impl<'a> UnitVariant<'a> {
+    /// Returns an expression for the discriminant of this variant.
+    pub fn discriminant(&self) -> TokenStream {
+        let lit = Literal::usize_unsuffixed(self.offset);
+        match self.base {
+            Some(expr) if self.offset == 0 => quote!(#expr),
+            Some(expr) => quote!((#expr) + #lit),
+            None => quote!(#lit),
+        }
+    }
+
+    /// Returns the value of the discriminant, if it is an integer literal (or the negation of one).
+    pub fn value(&self) -> Option<i128> {
+        let base = match self.base {
+            Some(expr) => literal_value(expr)?,
+            None => 0,
+        };
+        base.checked_add(self.offset as i128)
+    }
+}
+
+fn literal_value(expr: &Expr) -> Option<i128> {
+    match *expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(ref lit), .. }) => lit.base10_parse().ok(),
+        Expr::Unary(ExprUnary { op: UnOp::Neg(_), ref expr, .. }) => literal_value(expr)?.checked_neg(),
+        Expr::Paren(ExprParen { ref expr, .. }) | Expr::Group(ExprGroup { ref expr, .. }) => literal_value(expr),
+        _ => None,
+    }
+}
+
+/**
+The unit variants of an enumeration, along with their discriminants.
+
+Skipped variants are excluded, but still contribute to the discriminants of the variants that follow them.
+*/
+pub struct UnitVariants<'a> {
+    /// The integer type the discriminants are expressed in.
+    pub repr: Ident,
+    pub variants: Vec<UnitVariant<'a>>,
+    /// The variant marked `catch_all`, and the type of its field.
+    pub catch_all: Option<(&'a Ident, &'a Type)>,
+    /// Whether any variants were skipped.
+    pub skipped: bool,
+}
+
+impl<'a> UnitVariants<'a> {
+    /**
+    Collects the unit variants of `data`.
+
+    If `attr` is given, the variants' `#[attr(..)]` options are parsed: variants marked with `skip` are excluded, and a single tuple variant with one field may be marked `catch_all`.  Any other variants with fields are rejected; all such errors are reported together.
+    */
+    pub fn collect(input: &'a DeriveInput, data: &'a DataEnum, attr: Option<&str>) -> Result<UnitVariants<'a>> {
+        let repr = match int_repr(&input.attrs)? {
+            Some(ident) => ident,
+            None => Ident::new("isize", Span::call_site()),
+        };
+
+        let mut errors: Option<Error> = None;
+        let mut push_error = |e: Error| match errors {
+            Some(ref mut errors) => errors.combine(e),
+            None => errors = Some(e),
+        };
+
+        let mut variants = vec![];
+        let mut catch_all = None;
+        let mut skipped = false;
+        let mut base: Option<&Expr> = None;
+        let mut offset = 0usize;
+        for (i, variant) in data.variants.iter().enumerate() {
+            match variant.discriminant {
+                Some((_, ref expr)) => {
+                    base = Some(expr);
+                    offset = 0;
+                },
+                None if i > 0 => offset += 1,
+                None => (),
+            }
+
+            let opts = match attr {
+                Some(attr) => match VariantOptions::parse(variant, attr) {
+                    Ok(opts) => opts,
+                    Err(e) => {
+                        push_error(e);
+                        continue;
+                    }
+                },
+                None => VariantOptions::default(),
+            };
+            if opts.skip {
+                skipped = true;
+                continue;
+            }
+
+            if let Some(kw) = opts.catch_all {
+                match variant.fields {
+                    Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => (),
+                    ref fields => {
+                        push_error(Error::new_spanned(fields, "a `catch_all` variant must be a tuple variant with exactly one field"));
+                        continue;
+                    }
+                }
+                if catch_all.is_some() {
+                    push_error(Error::new(kw.span(), "only one variant can be marked `catch_all`"));
+                    continue;
+                }
+                catch_all = Some((&variant.ident, &variant.fields.iter().next().unwrap().ty));
+                continue;
+            }
+
+            if !matches!(variant.fields, Fields::Unit) {
+                let msg = match attr {
+                    Some(attr) => format!("only unit variants are supported; add `#[{}(skip)]` to exclude this variant", attr),
+                    None => "only unit variants are supported".to_owned(),
+                };
+                push_error(Error::new_spanned(&variant.fields, msg));
+                continue;
+            }
+
+            variants.push(UnitVariant { ident: &variant.ident, aliases: opts.aliases, base, offset });
+        }
+
+        match errors {
+            Some(e) => Err(e),
+            None => Ok(UnitVariants { repr, variants, catch_all, skipped }),
+        }
+    }
+
+    /// Defines a local `const` array named `name` holding the discriminants, in order.
+    pub fn discriminant_table(&self, name: &Ident) -> TokenStream {
+        self.discriminant_table_as(name, &self.repr)
+    }
+
+    /// Like `discriminant_table`, but with the discriminants expressed as `ty` rather than the `#[repr(..)]` type.
+    pub fn discriminant_table_as<T: ToTokens>(&self, name: &Ident, ty: T) -> TokenStream {
+        let len = self.variants.len();
+        let discrs = self.variants.iter().map(UnitVariant::discriminant);
+        quote! {
+            const #name: [#ty; #len] = [#(#discrs),*];
+        }
+    }
+
+    /// Returns the smallest and largest discriminants, if they are all literals.
+    pub fn value_range(&self) -> Option<(i128, i128)> {
+        let mut range: Option<(i128, i128)> = None;
+        for variant in &self.variants {
+            let v = variant.value()?;
+            range = Some(match range {
+                Some((lo, hi)) => (lo.min(v), hi.max(v)),
+                None => (v, v),
+            });
+        }
+        range
+    }
+}
+
+/// An identifier for use in generated code, prefixed so it is unlikely to clash with user identifiers.
+pub fn hidden_ident(name: &str) -> Ident {
+    Ident::new(&format!("__CONV_{}", name), Span::call_site())
+}
@@ -0,0 +1,201 @@
+/*!
+Implementation of `#[derive(Fieldwise)]`.
+*/
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Ident, LitStr, Result, Type};
+use syn::ext::IdentExt;
+
+use options::{qualify_scheme, Conversions, CONVERSION_OPTIONS};
+
+/// A field of the struct, along with the options given in its `#[fieldwise(..)]` attributes.
+struct Field<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+    /// Set by `scheme = Scheme`; overrides the scheme of approximate conversions.  This is kept as
+    /// written, so that errors point at it, and only qualified when used.
+    scheme: Option<Type>,
+}
+
+impl<'a> Field<'a> {
+    fn parse(field: &'a syn::Field) -> Result<Field<'a>> {
+        let mut scheme = None;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("fieldwise")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("scheme") {
+                    scheme = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `fieldwise` option on a field; expected `scheme = ..`"))
+                }
+            })?;
+        }
+        Ok(Field { ident: field.ident.as_ref().unwrap(), ty: &field.ty, scheme })
+    }
+
+    /// The name of the field as reported in errors, without any `r#` prefix.
+    fn name(&self) -> LitStr {
+        LitStr::new(&self.ident.unraw().to_string(), self.ident.span())
+    }
+}
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => fields.named.iter().map(Field::parse).collect::<Result<Vec<_>>>()?,
+            ref fields => return Err(Error::new_spanned(fields, "`Fieldwise` can only be derived for structs with named fields")),
+        },
+        Data::Enum(ref data) => return Err(Error::new_spanned(data.enum_token, "`Fieldwise` can only be derived for structs")),
+        Data::Union(ref data) => return Err(Error::new_spanned(data.union_token, "`Fieldwise` can only be derived for structs")),
+    };
+
+    let mut conv = Conversions::default();
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("fieldwise")) {
+        attr.parse_nested_meta(|meta| {
+            if conv.parse_meta(&meta)? {
+                Ok(())
+            } else {
+                Err(meta.error(format!("unsupported `fieldwise` option; expected one of {}", CONVERSION_OPTIONS)))
+            }
+        })?;
+    }
+    conv.finish();
+    if conv.is_empty() {
+        return Err(Error::new_spanned(&input.ident,
+            "no conversions to generate; list the types to convert with `#[fieldwise(value_from(..), value_into(..), ..)]`"));
+    }
+    // A field's scheme only applies to approximations; anything else would silently ignore it.
+    if conv.approx_from.is_empty() && conv.approx_into.is_empty() {
+        if let Some(scheme) = fields.iter().filter_map(|f| f.scheme.as_ref()).next() {
+            return Err(Error::new_spanned(scheme,
+                "`scheme` only applies to approximations, but no `approx_from` or `approx_into` conversions are generated"));
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let this = quote!(#name #ty_generics);
+
+    // Builds a struct literal of type `dst`, converting each field of `src` with `convert`.  Only
+    // the type of the fields of *this* struct are known, so the other side is left to inference;
+    // `NamedFieldError::convert` ties the type of the error's payload to that of the source field.
+    let construct = |dst: TokenStream, convert: &dyn Fn(&Field) -> TokenStream| {
+        let inits = fields.iter().map(|f| {
+            let ident = f.ident;
+            let field_name = f.name();
+            let conversion = convert(f);
+            quote! {
+                #ident: ::conv::errors::NamedFieldError::convert(#field_name, src.#ident, #conversion)?,
+            }
+        });
+        // A struct without fields never uses `src`.
+        let unused = if fields.is_empty() { Some(quote!(let _ = src;)) } else { None };
+        quote! {
+            #unused
+            ::std::result::Result::Ok(#dst { #(#inits)* })
+        }
+    };
+    let scheme_for = |f: &Field, scheme: &Type| match f.scheme {
+        Some(ref own) => {
+            let mut own = own.clone();
+            qualify_scheme(&mut own);
+            own
+        },
+        None => scheme.clone(),
+    };
+
+    let mut impls = vec![];
+
+    for src in &conv.value_from {
+        let body = construct(quote!(#name), &|f| {
+            let ty = f.ty;
+            quote!(<#ty as ::conv::ValueFrom<_>>::value_from)
+        });
+        impls.push(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::conv::ValueFrom<#src> for #this #where_clause {
+                type Err = ::conv::errors::NamedFieldError;
+                fn value_from(src: #src) -> ::std::result::Result<Self, Self::Err> { #body }
+            }
+        });
+    }
+
+    for src in &conv.approx_from {
+        for scheme in &conv.schemes {
+            let body = construct(quote!(#name), &|f| {
+                let ty = f.ty;
+                let scheme = scheme_for(f, scheme);
+                quote!(<#ty as ::conv::ApproxFrom<_, #scheme>>::approx_from)
+            });
+            impls.push(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::conv::ApproxFrom<#src, #scheme> for #this #where_clause {
+                    type Err = ::conv::errors::NamedFieldError;
+                    fn approx_from(src: #src) -> ::std::result::Result<Self, Self::Err> { #body }
+                }
+            });
+        }
+    }
+
+    for src in &conv.try_from {
+        let body = construct(quote!(#name), &|f| {
+            let ty = f.ty;
+            quote!(<#ty as ::conv::TryFrom<_>>::try_from)
+        });
+        impls.push(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::conv::TryFrom<#src> for #this #where_clause {
+                type Err = ::conv::errors::NamedFieldError;
+                fn try_from(src: #src) -> ::std::result::Result<Self, Self::Err> { #body }
+            }
+        });
+    }
+
+    for dst in &conv.value_into {
+        let body = construct(quote!(#dst), &|f| {
+            let ty = f.ty;
+            quote!(<#ty as ::conv::ValueInto<_>>::value_into)
+        });
+        impls.push(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::conv::ValueFrom<#this> for #dst #where_clause {
+                type Err = ::conv::errors::NamedFieldError;
+                fn value_from(src: #this) -> ::std::result::Result<Self, Self::Err> { #body }
+            }
+        });
+    }
+
+    for dst in &conv.approx_into {
+        for scheme in &conv.schemes {
+            let body = construct(quote!(#dst), &|f| {
+                let ty = f.ty;
+                let scheme = scheme_for(f, scheme);
+                quote!(<#ty as ::conv::ApproxInto<_, #scheme>>::approx_into)
+            });
+            impls.push(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::conv::ApproxFrom<#this, #scheme> for #dst #where_clause {
+                    type Err = ::conv::errors::NamedFieldError;
+                    fn approx_from(src: #this) -> ::std::result::Result<Self, Self::Err> { #body }
+                }
+            });
+        }
+    }
+
+    for dst in &conv.try_into {
+        let body = construct(quote!(#dst), &|f| {
+            let ty = f.ty;
+            quote!(<#ty as ::conv::TryInto<_>>::try_into)
+        });
+        impls.push(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::conv::TryFrom<#this> for #dst #where_clause {
+                type Err = ::conv::errors::NamedFieldError;
+                fn try_from(src: #this) -> ::std::result::Result<Self, Self::Err> { #body }
+            }
+        });
+    }
+
+    Ok(quote!(#(#impls)*))
+}
@@ -0,0 +1,389 @@
+/*!
+This crate provides custom derives for the traits in the [`conv`](https://crates.io/crates/conv) crate.
+
+The generated code refers to `::conv`, so the `conv` crate must also be linked into the crate using the derives.
+
+# `#[derive(TryFrom)]`
+
+Derives [`TryFrom`](https://danielkeep.github.io/rust-conv/doc/conv/trait.TryFrom.html) for enumerations consisting of unitary variants, with or without explicit discriminants.  A conversion succeeds if the source value equals the discriminant of a variant, and otherwise fails with `Unrepresentable(src)`.
+
+The types to convert from are listed with one or more `#[try_from(..)]` attributes.  If there are none, the integer type of the enumeration's `#[repr(..)]` attribute is used.
+
+```
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use conv::{TryFrom, Unrepresentable};
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[try_from(u8, i32)]
+pub(crate) enum Colours {
+    Red = 0,
+    Green = 5,
+    Blue,
+}
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[repr(i16)]
+enum Offset {
+    Behind = -1,
+    Level,
+    Ahead,
+}
+
+fn main() {
+    assert_eq!(Colours::try_from(5u8), Ok(Colours::Green));
+    assert_eq!(Colours::try_from(6i32), Ok(Colours::Blue));
+    assert_eq!(Colours::try_from(7i32), Err(Unrepresentable(7)));
+
+    assert_eq!(Offset::try_from(-1i16), Ok(Offset::Behind));
+    assert_eq!(Offset::try_from(2i16), Err(Unrepresentable(2)));
+}
+```
+
+A variant is only matched if its discriminant can be exactly represented by the source type; the discriminant is never truncated or wrapped.
+
+Variants with fields are rejected, unless they are marked with `#[try_from(skip)]`.  Skipped variants are never produced by the conversion, but still count towards the implicit discriminants of later variants.  This also allows generic enumerations to carry a marker variant:
+
+```
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use std::marker::PhantomData;
+use conv::{TryFrom, Unrepresentable};
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[try_from(u8)]
+enum Tagged<T> {
+    First,
+    #[try_from(skip)]
+    _Marker(PhantomData<T>),
+    Third,
+}
+
+fn main() {
+    assert_eq!(Tagged::<()>::try_from(0u8), Ok(Tagged::First));
+    assert_eq!(Tagged::<()>::try_from(1u8), Err(Unrepresentable(1)));
+    assert_eq!(Tagged::<()>::try_from(2u8), Ok(Tagged::Third));
+}
+```
+
+## Catch-all variants
+
+A single tuple variant with one field can be marked with `#[try_from(catch_all)]`.  Values which do not match the discriminant of any other variant are stored in it, so conversions from the field's type cannot fail, and use `NoError`.  Conversions from other integer types store the value if it can be converted to the field's type with `ValueFrom`, and otherwise fail with `Unrepresentable`.
+
+This also implements `ValueFrom<Enum>` for the field's type, which recovers the original value from either form.  As such, every discriminant must be representable by the field's type, and `catch_all` cannot be combined with `skip`.
+
+```
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use conv::{NoError, TryFrom, ValueFrom};
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[repr(u8)]
+enum Opcode {
+    Nop = 0x00,
+    Load = 0x10,
+    Store,
+    #[try_from(catch_all)]
+    Unknown(u8),
+}
+
+fn main() {
+    let op: Result<Opcode, NoError> = Opcode::try_from(0x11);
+    assert_eq!(op, Ok(Opcode::Store));
+    assert_eq!(Opcode::try_from(0x42), Ok(Opcode::Unknown(0x42)));
+
+    assert_eq!(u8::value_from(Opcode::Load), Ok(0x10));
+    assert_eq!(u8::value_from(Opcode::Unknown(0x42)), Ok(0x42));
+}
+```
+
+## String conversions
+
+If `&str` or `String` is listed as a source type, the conversion instead matches the *name* of each variant, failing with `Unrepresentable` if no variant matches; a `&str` source is copied into a `String` for the error.  Additional names for a variant can be given with `#[try_from(alias = "..")]`, and the matching can be made ASCII case-insensitive by adding `case_insensitive` to the enumeration's `#[try_from(..)]` attribute.  Names which would match more than one variant are rejected.
+
+This also implements `ValueFrom<Enum> for &'static str`, which produces the name of the variant.  Skipped and `catch_all` variants have no name to convert back from, so for them this fails with `Unrepresentable(src)`.
+
+```
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use conv::{TryFrom, Unrepresentable, ValueInto};
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[try_from(&str, String, case_insensitive)]
+enum Level {
+    Debug,
+    #[try_from(alias = "warn")]
+    Warning,
+    Error,
+}
+
+fn main() {
+    assert_eq!(Level::try_from("Debug"), Ok(Level::Debug));
+    assert_eq!(Level::try_from("ERROR"), Ok(Level::Error));
+    assert_eq!(Level::try_from("Warn"), Ok(Level::Warning));
+    assert_eq!(Level::try_from(String::from("warning")), Ok(Level::Warning));
+    assert_eq!(Level::try_from("trace"), Err(Unrepresentable("trace".to_owned())));
+
+    let name: &str = Level::Warning.value_into().unwrap();
+    assert_eq!(name, "Warning");
+}
+```
+
+Unsupported input is reported as a compile error pointing at the offending item:
+
+```compile_fail
+#[macro_use] extern crate conv_derive;
+
+#[derive(TryFrom)]
+#[try_from(u8)]
+enum Shape {
+    Point,
+    Circle(f32),
+}
+# fn main() {}
+```
+
+```compile_fail
+#[macro_use] extern crate conv_derive;
+
+#[derive(TryFrom)]
+#[try_from(&str, case_insensitive)]
+enum Toggle {
+    On,
+    // "ON" matches `On` once case is ignored.
+    #[try_from(alias = "ON")]
+    Enabled,
+}
+# fn main() {}
+```
+
+```compile_fail
+#[macro_use] extern crate conv_derive;
+
+// No `#[try_from(..)]` or `#[repr(..)]` to take the source type from.
+#[derive(TryFrom)]
+enum Direction { Left, Right }
+# fn main() {}
+```
+
+# `#[derive(ValueInto)]`
+
+Derives conversions from an enumeration of unitary variants into its discriminant.  Specifically, it implements [`ValueFrom`](https://danielkeep.github.io/rust-conv/doc/conv/trait.ValueFrom.html) and [`ApproxFrom`](https://danielkeep.github.io/rust-conv/doc/conv/trait.ApproxFrom.html) (with the default scheme) for every primitive integer type, making the enumeration usable with [`ValueInto`](https://danielkeep.github.io/rust-conv/doc/conv/trait.ValueInto.html) and [`ApproxInto`](https://danielkeep.github.io/rust-conv/doc/conv/trait.ApproxInto.html).
+
+The error type of each conversion is picked the same way as for the builtin integer conversions: `NoError` if every discriminant fits in the destination type, and otherwise `NegOverflow`, `PosOverflow` or `RangeError`, depending on which bounds can be exceeded.  The error carries the enumeration value.  If every discriminant is an integer literal, the actual discriminants are used to decide this; otherwise, the range of the `#[repr(..)]` type (or `isize`) is used.
+
+```
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use conv::{NoError, PosOverflow, ValueInto};
+
+#[derive(Debug, PartialEq, ValueInto)]
+#[repr(u16)]
+enum Port {
+    Http = 80,
+    Https = 443,
+}
+
+fn main() {
+    // The discriminant type itself can never fail.
+    let port: Result<u16, NoError> = Port::Https.value_into();
+    assert_eq!(port, Ok(443));
+
+    // Every discriminant fits in an `i16`...
+    let port: Result<i16, NoError> = Port::Http.value_into();
+    assert_eq!(port, Ok(80));
+
+    // ...but not in a `u8`.
+    let port: Result<u8, PosOverflow<Port>> = Port::Https.value_into();
+    assert_eq!(port, Err(PosOverflow(Port::Https)));
+}
+```
+
+# `#[derive(Newtype)]`
+
+Derives conversions for a struct with a single field by forwarding them to the field's type.  The conversions to forward are listed in one or more `#[conv(..)]` attributes:
+
+- `value_from(T, ..)`, `approx_from(T, ..)` and `try_from(T, ..)` implement `ValueFrom<T>`, `ApproxFrom<T, Scheme>` and `TryFrom<T>` for the struct.
+- `value_into(T, ..)`, `approx_into(T, ..)` and `try_into(T, ..)` implement `ValueFrom<Struct>`, `ApproxFrom<Struct, Scheme>` and `TryFrom<Struct>` for `T`.
+- `schemes(Scheme, ..)` lists the approximation schemes to implement `approx_from` and `approx_into` for.  It defaults to `DefaultApprox`.  The schemes defined by `conv` do not need to be imported.
+
+Each conversion uses the same error type as the field's conversion.
+
+```
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use conv::{ApproxFrom, FloatError, RoundToNearest, ValueFrom};
+
+#[derive(Copy, Clone, Debug, PartialEq, Newtype)]
+#[conv(value_from(f32, i32), approx_from(f64), value_into(f64), approx_into(i64))]
+#[conv(schemes(DefaultApprox, RoundToNearest))]
+struct Meters(f32);
+
+fn main() {
+    assert_eq!(Meters::value_from(1.5f32), Ok(Meters(1.5)));
+    assert_eq!(<Meters as ApproxFrom<_, RoundToNearest>>::approx_from(0.1f64), Ok(Meters(0.1)));
+    assert_eq!(f64::value_from(Meters(0.5)), Ok(0.5));
+    assert_eq!(<i64 as ApproxFrom<_, RoundToNearest>>::approx_from(Meters(2.5)), Ok(3));
+    assert_eq!(<i64 as ApproxFrom<_>>::approx_from(Meters(::std::f32::NAN)).map_err(|e| e.to_string()),
+        Err(FloatError::NotANumber(0.0f32).to_string()));
+}
+```
+
+## Validation
+
+`#[conv(validate = expr)]` adds a check to the conversions *into* the struct.  `expr` must be callable as `Fn(&Inner) -> bool`; if it returns `false`, the conversion fails with `GeneralError::Unrepresentable(src)`.  As this changes the error type, conversions into a validated struct instead fail with `GeneralError<T>`, into which the field's conversion error is converted.
+
+```
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use conv::{GeneralError, ValueFrom};
+
+#[derive(Debug, PartialEq, Newtype)]
+#[conv(value_from(i64, u8), validate = |v: &u32| *v <= 1000)]
+struct Millis {
+    ms: u32,
+}
+
+fn main() {
+    assert_eq!(Millis::value_from(250i64), Ok(Millis { ms: 250 }));
+    assert_eq!(Millis::value_from(-1i64), Err(GeneralError::NegOverflow(-1)));
+    assert_eq!(Millis::value_from(1001i64), Err(GeneralError::Unrepresentable(1001)));
+    assert_eq!(Millis::value_from(7u8), Ok(Millis { ms: 7 }));
+}
+```
+
+# `#[derive(Fieldwise)]`
+
+Derives conversions between a struct with named fields and another struct with the same field names, converting each field with the corresponding trait.  The conversions are listed in one or more `#[fieldwise(..)]` attributes, using the same `value_from`, `approx_from`, `try_from`, `value_into`, `approx_into`, `try_into` and `schemes` options as `#[derive(Newtype)]`.
+
+When converting *into* the deriving struct, the source only needs to have the struct's fields; any others are ignored.  When converting *from* it, the destination must have exactly the same fields.
+
+A field can be given its own approximation scheme with `#[fieldwise(scheme = Scheme)]`, which it then uses regardless of the scheme the struct as a whole is being converted with.  The scheme only applies to the `approx_from` and `approx_into` conversions, and is rejected if there are none.
+
+Every conversion fails with `NamedFieldError`, which holds the name of the first field that could not be converted, and its error as a `GeneralError` carrying the field's value in a `Box<dyn Any>`.  As such, the fields being converted must be `'static`, `Send` and `Sync`.
+
+```
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use conv::{ApproxFrom, GeneralErrorKind, NamedFieldError, ValueFrom};
+
+struct Wire {
+    id: u64,
+    sequence: u32,
+    level: f64,
+}
+
+#[derive(Debug, PartialEq, Fieldwise)]
+#[fieldwise(approx_from(Wire), value_into(Wire))]
+struct Sample {
+    id: u32,
+    #[fieldwise(scheme = Wrapping)]
+    sequence: u16,
+    #[fieldwise(scheme = RoundToNearest)]
+    level: i32,
+}
+
+fn main() {
+    let wire = Wire { id: 7, sequence: 0x1_0002, level: 2.5 };
+    assert_eq!(Sample::approx_from(wire).unwrap(), Sample { id: 7, sequence: 2, level: 3 });
+
+    let wire = Wire { id: 1 << 32, sequence: 0, level: 0.0 };
+    let e: NamedFieldError = Sample::approx_from(wire).unwrap_err();
+    assert_eq!((e.field, e.kind(), e.value::<u64>()), ("id", GeneralErrorKind::PosOverflow, Some(&(1 << 32))));
+
+    let wire = Wire::value_from(Sample { id: 1, sequence: 2, level: -3 }).unwrap();
+    assert_eq!((wire.id, wire.sequence, wire.level), (1, 2, -3.0));
+}
+```
+
+```compile_fail
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+struct Wire { sequence: u32 }
+
+// Value conversions never use a scheme, so it would be ignored.
+#[derive(Fieldwise)]
+#[fieldwise(value_from(Wire))]
+struct Sample {
+    #[fieldwise(scheme = Wrapping)]
+    sequence: u16,
+}
+# fn main() {}
+```
+*/
+
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use syn::DeriveInput;
+
+mod enums;
+mod fieldwise;
+mod newtype;
+mod options;
+mod try_from;
+mod value_into;
+
+/**
+See the crate documentation for details.
+*/
+#[proc_macro_derive(TryFrom, attributes(try_from))]
+pub fn derive_try_from(input: TokenStream) -> TokenStream {
+    expand(input, try_from::derive)
+}
+
+/**
+See the crate documentation for details.
+*/
+#[proc_macro_derive(ValueInto)]
+pub fn derive_value_into(input: TokenStream) -> TokenStream {
+    expand(input, value_into::derive)
+}
+
+/**
+See the crate documentation for details.
+*/
+#[proc_macro_derive(Newtype, attributes(conv))]
+pub fn derive_newtype(input: TokenStream) -> TokenStream {
+    expand(input, newtype::derive)
+}
+
+/**
+See the crate documentation for details.
+*/
+#[proc_macro_derive(Fieldwise, attributes(fieldwise))]
+pub fn derive_fieldwise(input: TokenStream) -> TokenStream {
+    expand(input, fieldwise::derive)
+}
+
+fn expand(input: TokenStream, derive: fn(&DeriveInput) -> syn::Result<proc_macro2::TokenStream>) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    derive(&input)
+        .unwrap_or_else(compile_errors)
+        .into()
+}
+
+/**
+Converts `err` into `compile_error!` invocations.
+
+This avoids `syn::Error::into_compile_error`, which refers to `::core`; that path does not resolve in 2015 edition crates.
+*/
+fn compile_errors(err: syn::Error) -> proc_macro2::TokenStream {
+    let errors = err.into_iter().map(|e| {
+        let msg = e.to_string();
+        quote::quote_spanned!(e.span()=> compile_error!(#msg);)
+    });
+    quote::quote!(#(#errors)*)
+}
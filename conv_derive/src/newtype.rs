@@ -0,0 +1,188 @@
+/*!
+Implementation of `#[derive(Newtype)]`.
+*/
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Expr, Fields, Member, Result, Type, WherePredicate};
+
+use options::{Conversions, CONVERSION_OPTIONS};
+
+/// The options given in `#[conv(..)]` attributes.
+struct Options {
+    conv: Conversions,
+    validate: Option<Expr>,
+}
+
+impl Options {
+    fn parse(input: &DeriveInput) -> Result<Options> {
+        let mut conv = Conversions::default();
+        let mut validate = None;
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("conv")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("validate") {
+                    validate = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if conv.parse_meta(&meta)? {
+                    Ok(())
+                } else {
+                    Err(meta.error(format!("unsupported `conv` option; expected one of {} or `validate`", CONVERSION_OPTIONS)))
+                }
+            })?;
+        }
+        conv.finish();
+        Ok(Options { conv, validate })
+    }
+}
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let (member, inner) = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => (Member::from(0), &fields.unnamed[0].ty),
+            Fields::Named(ref fields) if fields.named.len() == 1 => {
+                let field = &fields.named[0];
+                (Member::from(field.ident.clone().unwrap()), &field.ty)
+            },
+            ref fields => return Err(Error::new_spanned(fields, "`Newtype` can only be derived for structs with exactly one field")),
+        },
+        Data::Enum(ref data) => return Err(Error::new_spanned(data.enum_token, "`Newtype` can only be derived for structs")),
+        Data::Union(ref data) => return Err(Error::new_spanned(data.union_token, "`Newtype` can only be derived for structs")),
+    };
+
+    let opts = Options::parse(input)?;
+    if opts.conv.is_empty() {
+        return Err(Error::new_spanned(&input.ident,
+            "no conversions to forward; list the types to convert with `#[conv(value_from(..), value_into(..), ..)]`"));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let this = quote!(#name #ty_generics);
+    let construct = quote!(#name { #member: inner });
+
+    // Each impl gets the bounds needed to forward to the inner type, so that generic newtypes work.
+    let where_clause = |extra: Vec<WherePredicate>| {
+        let mut clause = input.generics.where_clause.clone()
+            .unwrap_or_else(|| syn::parse_quote!(where));
+        clause.predicates.extend(extra);
+        clause
+    };
+
+    // The body and error type of a conversion into the newtype, given the forwarding call.
+    let from_impl = |trait_: TokenStream, method: TokenStream, src: &Type| {
+        let inner_err = quote!(<#inner as #trait_>::Err);
+        let (err, body, mut preds) = match opts.validate {
+            None => (
+                inner_err.clone(),
+                quote! {
+                    let inner = <#inner as #trait_>::#method(src)?;
+                    ::std::result::Result::Ok(#construct)
+                },
+                vec![],
+            ),
+            Some(ref validate) => (
+                quote!(::conv::errors::GeneralError<#src>),
+                quote! {
+                    let inner = <#inner as #trait_>::#method(::std::clone::Clone::clone(&src))
+                        .map_err(::std::convert::Into::<::conv::errors::GeneralError<#src>>::into)?;
+                    if !(#validate)(&inner) {
+                        return ::std::result::Result::Err(::conv::errors::GeneralError::Unrepresentable(src));
+                    }
+                    ::std::result::Result::Ok(#construct)
+                },
+                vec![
+                    syn::parse_quote!(#src: ::std::clone::Clone),
+                    syn::parse_quote!(#inner_err: ::std::convert::Into<::conv::errors::GeneralError<#src>>),
+                ],
+            ),
+        };
+        preds.insert(0, syn::parse_quote!(#inner: #trait_));
+        (err, body, where_clause(preds))
+    };
+
+    let mut impls = vec![];
+
+    for src in &opts.conv.value_from {
+        let (err, body, where_clause) = from_impl(quote!(::conv::ValueFrom<#src>), quote!(value_from), src);
+        impls.push(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::conv::ValueFrom<#src> for #this #where_clause {
+                type Err = #err;
+                #[inline]
+                fn value_from(src: #src) -> ::std::result::Result<Self, Self::Err> { #body }
+            }
+        });
+    }
+
+    for src in &opts.conv.approx_from {
+        for scheme in &opts.conv.schemes {
+            let (err, body, where_clause) = from_impl(quote!(::conv::ApproxFrom<#src, #scheme>), quote!(approx_from), src);
+            impls.push(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::conv::ApproxFrom<#src, #scheme> for #this #where_clause {
+                    type Err = #err;
+                    #[inline]
+                    fn approx_from(src: #src) -> ::std::result::Result<Self, Self::Err> { #body }
+                }
+            });
+        }
+    }
+
+    for src in &opts.conv.try_from {
+        let (err, body, where_clause) = from_impl(quote!(::conv::TryFrom<#src>), quote!(try_from), src);
+        impls.push(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::conv::TryFrom<#src> for #this #where_clause {
+                type Err = #err;
+                #[inline]
+                fn try_from(src: #src) -> ::std::result::Result<Self, Self::Err> { #body }
+            }
+        });
+    }
+
+    for dst in &opts.conv.value_into {
+        let where_clause = where_clause(vec![syn::parse_quote!(#dst: ::conv::ValueFrom<#inner>)]);
+        impls.push(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::conv::ValueFrom<#this> for #dst #where_clause {
+                type Err = <#dst as ::conv::ValueFrom<#inner>>::Err;
+                #[inline]
+                fn value_from(src: #this) -> ::std::result::Result<Self, Self::Err> {
+                    <#dst as ::conv::ValueFrom<#inner>>::value_from(src.#member)
+                }
+            }
+        });
+    }
+
+    for dst in &opts.conv.approx_into {
+        for scheme in &opts.conv.schemes {
+            let where_clause = where_clause(vec![syn::parse_quote!(#dst: ::conv::ApproxFrom<#inner, #scheme>)]);
+            impls.push(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::conv::ApproxFrom<#this, #scheme> for #dst #where_clause {
+                    type Err = <#dst as ::conv::ApproxFrom<#inner, #scheme>>::Err;
+                    #[inline]
+                    fn approx_from(src: #this) -> ::std::result::Result<Self, Self::Err> {
+                        <#dst as ::conv::ApproxFrom<#inner, #scheme>>::approx_from(src.#member)
+                    }
+                }
+            });
+        }
+    }
+
+    for dst in &opts.conv.try_into {
+        let where_clause = where_clause(vec![syn::parse_quote!(#dst: ::conv::TryFrom<#inner>)]);
+        impls.push(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::conv::TryFrom<#this> for #dst #where_clause {
+                type Err = <#dst as ::conv::TryFrom<#inner>>::Err;
+                #[inline]
+                fn try_from(src: #this) -> ::std::result::Result<Self, Self::Err> {
+                    <#dst as ::conv::TryFrom<#inner>>::try_from(src.#member)
+                }
+            }
+        });
+    }
+
+    Ok(quote!(#(#impls)*))
+}
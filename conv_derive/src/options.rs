@@ -0,0 +1,81 @@
+/*!
+Helpers shared by the derives that operate on structs.
+*/
+
+use syn::{Result, Type};
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+
+/// The names of the approximation schemes defined by `conv`, which need not be imported.
+const SCHEMES: &[&str] = &[
+    "DefaultApprox", "RoundToNearest", "RoundHalfEven", "RoundToNegInf", "RoundToPosInf", "RoundToZero",
+    "Wrapping", "Saturating",
+];
+
+/// The names of the options parsed by `Conversions`, for use in error messages.
+pub const CONVERSION_OPTIONS: &str = "`value_from`, `approx_from`, `try_from`, `value_into`, `approx_into`, `try_into`, `schemes`";
+
+/// The conversions listed in a struct's attributes, as `value_from(Type, ..)`, `schemes(Scheme, ..)` and so on.
+#[derive(Default)]
+pub struct Conversions {
+    pub value_from: Vec<Type>,
+    pub approx_from: Vec<Type>,
+    pub try_from: Vec<Type>,
+    pub value_into: Vec<Type>,
+    pub approx_into: Vec<Type>,
+    pub try_into: Vec<Type>,
+    pub schemes: Vec<Type>,
+}
+
+impl Conversions {
+    /// Parses `meta` if it is one of the conversion lists, returning `false` if it is not.
+    pub fn parse_meta(&mut self, meta: &ParseNestedMeta) -> Result<bool> {
+        let list = if meta.path.is_ident("value_from") {
+            &mut self.value_from
+        } else if meta.path.is_ident("approx_from") {
+            &mut self.approx_from
+        } else if meta.path.is_ident("try_from") {
+            &mut self.try_from
+        } else if meta.path.is_ident("value_into") {
+            &mut self.value_into
+        } else if meta.path.is_ident("approx_into") {
+            &mut self.approx_into
+        } else if meta.path.is_ident("try_into") {
+            &mut self.try_into
+        } else if meta.path.is_ident("schemes") {
+            &mut self.schemes
+        } else {
+            return Ok(false);
+        };
+        let content;
+        syn::parenthesized!(content in meta.input);
+        list.extend(Punctuated::<Type, syn::Token![,]>::parse_terminated(&content)?);
+        Ok(true)
+    }
+
+    /// Fills in the default scheme if none were given, and qualifies the names of `conv`'s own schemes.
+    pub fn finish(&mut self) {
+        if self.schemes.is_empty() {
+            self.schemes.push(syn::parse_quote!(::conv::DefaultApprox));
+        }
+        for scheme in &mut self.schemes {
+            qualify_scheme(scheme);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value_from.is_empty() && self.approx_from.is_empty() && self.try_from.is_empty()
+            && self.value_into.is_empty() && self.approx_into.is_empty() && self.try_into.is_empty()
+    }
+}
+
+/// Prefixes `scheme` with `::conv::` if it names one of `conv`'s approximation schemes.
+pub fn qualify_scheme(scheme: &mut Type) {
+    if let Type::Path(ref mut p) = *scheme {
+        if p.qself.is_none() && p.path.segments.len() == 1
+            && SCHEMES.iter().any(|s| p.path.segments[0].ident == s)
+        {
+            p.path = syn::parse_quote!(::conv::#p);
+        }
+    }
+}
@@ -0,0 +1,336 @@
+/*!
+Implementation of `#[derive(TryFrom)]`.
+*/
+
+use std::collections::HashMap;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_quote, DeriveInput, Error, GenericParam, Generics, Lifetime, LifetimeParam, LitStr, Result, Type};
+
+use enums::{enum_data, hidden_ident, int_repr, type_list, UnitVariants};
+
+/// The kinds of source type the derive knows how to convert from.
+enum Source<'a> {
+    /// Matched against the discriminants.
+    Int(&'a Type),
+    /// `&str`, possibly with an explicit lifetime; matched against the variant names.
+    Str(Option<&'a Lifetime>),
+    /// `String`; matched against the variant names.
+    String(&'a Type),
+}
+
+impl<'a> Source<'a> {
+    fn classify(ty: &'a Type) -> Source<'a> {
+        match *ty {
+            Type::Reference(ref r) if r.mutability.is_none() && is_ident(&r.elem, "str") => Source::Str(r.lifetime.as_ref()),
+            ref ty if is_ident(ty, "String") => Source::String(ty),
+            ref ty => Source::Int(ty),
+        }
+    }
+}
+
+/// Returns `true` if `ty` is a path whose last segment is `name`, without arguments.
+fn is_ident(ty: &Type, name: &str) -> bool {
+    match *ty {
+        Type::Path(ref p) if p.qself.is_none() => p.path.segments.last()
+            .is_some_and(|seg| seg.ident == name && seg.arguments.is_empty()),
+        _ => false,
+    }
+}
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let data = enum_data(input, "TryFrom")?;
+
+    // `case_insensitive` is accepted amongst the source types.
+    let mut case_insensitive = None;
+    let mut srcs = vec![];
+    for ty in type_list(&input.attrs, "try_from")? {
+        if is_ident(&ty, "case_insensitive") {
+            case_insensitive = Some(ty);
+        } else {
+            srcs.push(ty);
+        }
+    }
+    if srcs.is_empty() {
+        match int_repr(&input.attrs)? {
+            Some(repr) => srcs.push(Type::Verbatim(quote!(#repr))),
+            None => return Err(Error::new_spanned(&input.ident,
+                "cannot infer the type to convert from; add `#[try_from(Type, ...)]` or an integer `#[repr(..)]`")),
+        }
+    }
+    let srcs: Vec<_> = srcs.iter().map(Source::classify).collect();
+    let has_strings = srcs.iter().any(|s| !matches!(*s, Source::Int(_)));
+    if let Some(ty) = case_insensitive.as_ref().filter(|_| !has_strings) {
+        return Err(Error::new_spanned(ty, "`case_insensitive` requires a `&str` or `String` source type"));
+    }
+
+    let variants = UnitVariants::collect(input, data, Some("try_from"))?;
+    if let Some((ident, _)) = variants.catch_all.filter(|_| variants.skipped) {
+        return Err(Error::new_spanned(ident,
+            "a `catch_all` variant cannot be combined with `skip`, as every variant must convert back to the field type"));
+    }
+    let names = if has_strings {
+        Some(StrNames::collect(&variants, case_insensitive.is_some())?)
+    } else {
+        None
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut impls = vec![];
+    for src in &srcs {
+        impls.push(match *src {
+            Source::Int(src) => int_impl(input, &variants, src),
+            Source::Str(lifetime) => {
+                let names = names.as_ref().unwrap();
+                let (generics, lifetime) = match lifetime {
+                    Some(lt) => (input.generics.clone(), lt.clone()),
+                    None => with_lifetime(&input.generics),
+                };
+                let (impl_generics, _, _) = generics.split_for_impl();
+                let matcher = names.matcher(quote!(src));
+                quote! {
+                    #[automatically_derived]
+                    impl #impl_generics ::conv::TryFrom<&#lifetime str> for #name #ty_generics #where_clause {
+                        type Err = ::conv::errors::Unrepresentable<::std::string::String>;
+
+                        fn try_from(src: &#lifetime str) -> ::std::result::Result<Self, Self::Err> {
+                            match #matcher {
+                                ::std::option::Option::Some(v) => ::std::result::Result::Ok(v),
+                                ::std::option::Option::None => ::std::result::Result::Err(::conv::errors::Unrepresentable(src.to_owned())),
+                            }
+                        }
+                    }
+                }
+            },
+            Source::String(src) => {
+                let matcher = names.as_ref().unwrap().matcher(quote!(&src[..]));
+                quote! {
+                    #[automatically_derived]
+                    impl #impl_generics ::conv::TryFrom<#src> for #name #ty_generics #where_clause {
+                        type Err = ::conv::errors::Unrepresentable<#src>;
+
+                        fn try_from(src: #src) -> ::std::result::Result<Self, Self::Err> {
+                            match #matcher {
+                                ::std::option::Option::Some(v) => ::std::result::Result::Ok(v),
+                                ::std::option::Option::None => ::std::result::Result::Err(::conv::errors::Unrepresentable(src)),
+                            }
+                        }
+                    }
+                }
+            },
+        });
+    }
+
+    if let Some((ident, field)) = variants.catch_all {
+        impls.push(catch_all_reverse_impl(input, &variants, ident, field));
+    }
+
+    if has_strings {
+        impls.push(str_reverse_impl(input, &variants));
+    }
+
+    Ok(quote!(#(#impls)*))
+}
+
+fn int_impl(input: &DeriveInput, variants: &UnitVariants, src: &Type) -> TokenStream {
+    let repr = &variants.repr;
+    let table_name = hidden_ident("DISCRIMINANTS");
+    let table = variants.discriminant_table(&table_name);
+    let indices = 0..variants.variants.len();
+    let names = variants.variants.iter().map(|v| v.ident);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // Values which do not match a named variant go to the catch-all variant, if there is one.  If
+    // the source is the catch-all's field type, this cannot fail.
+    let (err, fallback) = match variants.catch_all {
+        Some((ident, field)) if same_type(src, field) => (
+            quote!(::conv::NoError),
+            quote!(::std::result::Result::Ok(Self::#ident(src))),
+        ),
+        Some((ident, field)) => (
+            quote!(::conv::errors::Unrepresentable<#src>),
+            quote! {
+                match <#field as ::conv::ValueFrom<#src>>::value_from(src) {
+                    ::std::result::Result::Ok(v) => ::std::result::Result::Ok(Self::#ident(v)),
+                    ::std::result::Result::Err(_) => ::std::result::Result::Err(::conv::errors::Unrepresentable(src)),
+                }
+            },
+        ),
+        None => (
+            quote!(::conv::errors::Unrepresentable<#src>),
+            quote!(::std::result::Result::Err(::conv::errors::Unrepresentable(src))),
+        ),
+    };
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::conv::TryFrom<#src> for #name #ty_generics #where_clause {
+            type Err = #err;
+
+            fn try_from(src: #src) -> ::std::result::Result<Self, Self::Err> {
+                #table
+                #(
+                    if <#src as ::conv::ValueFrom<#repr>>::value_from(#table_name[#indices]).ok()
+                        == ::std::option::Option::Some(src)
+                    {
+                        return ::std::result::Result::Ok(Self::#names);
+                    }
+                )*
+                #fallback
+            }
+        }
+    }
+}
+
+/**
+Generates the conversion from the enumeration back into the field type of its catch-all variant.
+
+Named variants produce their discriminant, which must be representable by the field type.
+*/
+fn catch_all_reverse_impl(input: &DeriveInput, variants: &UnitVariants, catch_all: &syn::Ident, field: &Type) -> TokenStream {
+    let table_name = hidden_ident("DISCRIMINANTS");
+    let table = variants.discriminant_table_as(&table_name, field);
+    let name = &input.ident;
+    let arms = variants.variants.iter().enumerate().map(|(i, v)| {
+        let ident = v.ident;
+        quote!(#name::#ident => #table_name[#i],)
+    });
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::conv::ValueFrom<#name #ty_generics> for #field #where_clause {
+            type Err = ::conv::NoError;
+
+            #[inline]
+            fn value_from(src: #name #ty_generics) -> ::std::result::Result<#field, Self::Err> {
+                #table
+                ::std::result::Result::Ok(match src {
+                    #(#arms)*
+                    #name::#catch_all(v) => v,
+                })
+            }
+        }
+    }
+}
+
+/**
+Generates the conversion from the enumeration back into the name of its variant.
+
+Only variants which can be parsed from their name convert; skipped and catch-all variants fail with `Unrepresentable`, so that every name produced converts back to the same variant.
+*/
+fn str_reverse_impl(input: &DeriveInput, variants: &UnitVariants) -> TokenStream {
+    let name = &input.ident;
+    let arms = variants.variants.iter().map(|v| {
+        let ident = v.ident;
+        let s = LitStr::new(&ident.to_string(), ident.span());
+        quote!(#name::#ident => ::std::result::Result::Ok(#s),)
+    });
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let mut generics = input.generics.clone();
+    let (err, fallback) = if variants.skipped || variants.catch_all.is_some() {
+        // `Unrepresentable<T>` is only an error if `T: Any`.
+        generics.make_where_clause().predicates.push(parse_quote!(#name #ty_generics: 'static));
+        (
+            quote!(::conv::errors::Unrepresentable<#name #ty_generics>),
+            quote!(src => ::std::result::Result::Err(::conv::errors::Unrepresentable(src)),),
+        )
+    } else {
+        (quote!(::conv::NoError), quote!())
+    };
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::conv::ValueFrom<#name #ty_generics> for &'static str #where_clause {
+            type Err = #err;
+
+            #[inline]
+            fn value_from(src: #name #ty_generics) -> ::std::result::Result<&'static str, Self::Err> {
+                match src {
+                    #(#arms)*
+                    #fallback
+                }
+            }
+        }
+    }
+}
+
+/// Returns `true` if `a` and `b` are written identically.
+fn same_type(a: &Type, b: &Type) -> bool {
+    quote!(#a).to_string() == quote!(#b).to_string()
+}
+
+/// Adds a fresh lifetime parameter to `generics`, for borrowed source types.
+fn with_lifetime(generics: &Generics) -> (Generics, Lifetime) {
+    let lifetime = Lifetime::new("'__conv_src", Span::call_site());
+    let mut generics = generics.clone();
+    generics.params.insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+    (generics, lifetime)
+}
+
+/// The strings each variant can be parsed from.
+struct StrNames<'a> {
+    case_insensitive: bool,
+    variants: Vec<(&'a syn::Ident, Vec<LitStr>)>,
+}
+
+impl<'a> StrNames<'a> {
+    /// Collects the names and aliases of each variant, rejecting any which would be ambiguous.
+    fn collect(variants: &UnitVariants<'a>, case_insensitive: bool) -> Result<StrNames<'a>> {
+        let mut seen: HashMap<String, &syn::Ident> = HashMap::new();
+        let mut errors: Option<Error> = None;
+        let mut result = vec![];
+        for variant in &variants.variants {
+            let mut strs = vec![LitStr::new(&variant.ident.to_string(), variant.ident.span())];
+            strs.extend(variant.aliases.iter().cloned());
+            for s in &strs {
+                let key = if case_insensitive { s.value().to_ascii_lowercase() } else { s.value() };
+                if let Some(other) = seen.insert(key, variant.ident) {
+                    let e = Error::new(s.span(), format!("{:?} already refers to variant `{}`", s.value(), other));
+                    match errors {
+                        Some(ref mut errors) => errors.combine(e),
+                        None => errors = Some(e),
+                    }
+                }
+            }
+            result.push((variant.ident, strs));
+        }
+        match errors {
+            Some(e) => Err(e),
+            None => Ok(StrNames { case_insensitive, variants: result }),
+        }
+    }
+
+    /// Returns an expression which matches the `&str` expression `src`, producing an `Option<Self>`.
+    fn matcher(&self, src: TokenStream) -> TokenStream {
+        if self.case_insensitive {
+            let arms = self.variants.iter().map(|&(ident, ref strs)| quote! {
+                if #(s.eq_ignore_ascii_case(#strs))||* {
+                    ::std::option::Option::Some(Self::#ident)
+                } else
+            });
+            quote! {{
+                let s: &str = #src;
+                #(#arms)* {
+                    ::std::option::Option::None
+                }
+            }}
+        } else {
+            let arms = self.variants.iter().map(|&(ident, ref strs)| quote! {
+                #(#strs)|* => ::std::option::Option::Some(Self::#ident),
+            });
+            quote! {
+                match #src {
+                    #(#arms)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+/*!
+Implementation of `#[derive(ValueInto)]`.
+*/
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{DeriveInput, Ident, Result};
+
+use enums::{enum_data, hidden_ident, UnitVariants, INT_TYPES};
+
+/// The range of an integer type, for the given pointer width.  The upper bound is `None` for `u128`, as it does not fit in an `i128`.
+fn int_range(ty: &str, ptr_width: u32) -> (i128, Option<i128>) {
+    let (signed, bits) = match ty {
+        "isize" => (true, ptr_width),
+        "usize" => (false, ptr_width),
+        _ => (ty.starts_with('i'), ty[1..].parse().unwrap()),
+    };
+    match (signed, bits) {
+        (false, 128) => (0, None),
+        (false, bits) => (0, Some((1i128 << bits) - 1)),
+        (true, 128) => (i128::MIN, Some(i128::MAX)),
+        (true, bits) => (-(1i128 << (bits - 1)), Some((1i128 << (bits - 1)) - 1)),
+    }
+}
+
+/// Which ways a conversion can overflow, and so which error type it needs.
+#[derive(Copy, Clone, PartialEq)]
+enum Overflow {
+    None,
+    Neg,
+    Pos,
+    Both,
+}
+
+impl Overflow {
+    fn between(src: (i128, Option<i128>), dst: (i128, Option<i128>)) -> Overflow {
+        let neg = src.0 < dst.0;
+        let pos = match (src.1, dst.1) {
+            (_, None) => false,
+            (None, Some(_)) => true,
+            (Some(s), Some(d)) => s > d,
+        };
+        match (neg, pos) {
+            (false, false) => Overflow::None,
+            (true, false) => Overflow::Neg,
+            (false, true) => Overflow::Pos,
+            (true, true) => Overflow::Both,
+        }
+    }
+}
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let data = enum_data(input, "ValueInto")?;
+    let variants = UnitVariants::collect(input, data, None)?;
+    let repr = &variants.repr;
+    let repr_name = repr.to_string();
+
+    // The range the discriminants are known to lie in, if any.  If the
+    // discriminants are not all literals, fall back on the range of the
+    // representation type.
+    let known_range = variants.value_range().map(|(lo, hi)| (lo, Some(hi)));
+    let src_range = |ptr_width| known_range.unwrap_or_else(|| int_range(&repr_name, ptr_width));
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let src_ty = quote!(#name #ty_generics);
+
+    let table_name = hidden_ident("DISCRIMINANTS");
+    let table = variants.discriminant_table(&table_name);
+    let arms = variants.variants.iter().enumerate().map(|(i, v)| {
+        let ident = v.ident;
+        quote!(#name::#ident => #table_name[#i],)
+    });
+    let discriminant = quote! {
+        #table
+        let discr: #repr = match src { #(#arms)* };
+    };
+
+    let mut impls = vec![];
+    for dst_name in INT_TYPES {
+        let dst = Ident::new(dst_name, Span::call_site());
+
+        // Pick the narrowest error type possible, which may depend on the pointer width.
+        let widths: Vec<_> = [32, 64].iter()
+            .map(|&w| (w, Overflow::between(src_range(w), int_range(dst_name, w))))
+            .collect();
+        let cases: Vec<(Option<u32>, Overflow)> = if variants.variants.is_empty() {
+            vec![(None, Overflow::None)]
+        } else if widths[0].1 == widths[1].1 {
+            vec![(None, widths[0].1)]
+        } else {
+            widths.iter().map(|&(w, o)| (Some(w), o)).collect()
+        };
+
+        for (ptr_width, overflow) in cases {
+            let cfg = ptr_width.map(|w| {
+                let w = w.to_string();
+                quote!(#[cfg(target_pointer_width = #w)])
+            });
+
+            let (err, body) = if variants.variants.is_empty() {
+                (quote!(::conv::NoError), quote!(match src {}))
+            } else {
+                let cast = if *dst_name == repr_name { quote!(discr) } else { quote!(discr as #dst) };
+                let min = quote!(<#dst as ::conv::ConvBounds<#repr>>::min_src());
+                let max = quote!(<#dst as ::conv::ConvBounds<#repr>>::max_src());
+                match overflow {
+                    Overflow::None => (quote!(::conv::NoError), quote! {
+                        #discriminant
+                        ::std::result::Result::Ok(#cast)
+                    }),
+                    Overflow::Neg => (quote!(::conv::errors::NegOverflow<#src_ty>), quote! {
+                        #discriminant
+                        if discr < #min {
+                            return ::std::result::Result::Err(::conv::errors::NegOverflow(src));
+                        }
+                        ::std::result::Result::Ok(#cast)
+                    }),
+                    Overflow::Pos => (quote!(::conv::errors::PosOverflow<#src_ty>), quote! {
+                        #discriminant
+                        if discr > #max {
+                            return ::std::result::Result::Err(::conv::errors::PosOverflow(src));
+                        }
+                        ::std::result::Result::Ok(#cast)
+                    }),
+                    Overflow::Both => (quote!(::conv::errors::RangeError<#src_ty>), quote! {
+                        #discriminant
+                        if discr < #min {
+                            return ::std::result::Result::Err(::conv::errors::RangeError::NegOverflow(src));
+                        }
+                        if discr > #max {
+                            return ::std::result::Result::Err(::conv::errors::RangeError::PosOverflow(src));
+                        }
+                        ::std::result::Result::Ok(#cast)
+                    }),
+                }
+            };
+
+            impls.push(quote! {
+                #cfg
+                #[automatically_derived]
+                impl #impl_generics ::conv::ValueFrom<#src_ty> for #dst #where_clause {
+                    type Err = #err;
+
+                    #[inline]
+                    fn value_from(src: #src_ty) -> ::std::result::Result<#dst, Self::Err> {
+                        #body
+                    }
+                }
+
+                #cfg
+                #[automatically_derived]
+                impl #impl_generics ::conv::ApproxFrom<#src_ty, ::conv::DefaultApprox> for #dst #where_clause {
+                    type Err = #err;
+
+                    #[inline]
+                    fn approx_from(src: #src_ty) -> ::std::result::Result<#dst, Self::Err> {
+                        <#dst as ::conv::ValueFrom<#src_ty>>::value_from(src)
+                    }
+                }
+            });
+        }
+    }
+
+    Ok(quote!(#(#impls)*))
+}
@@ -0,0 +1,313 @@
+/*!
+This module defines bulk conversions between slices of the builtin numeric types.
+
+Converting a slice one element at a time with `ValueFrom` or `ApproxFrom` means every element goes through the full set of range and NaN checks, which gets in the way of the compiler vectorising the loop.  The conversions in this module instead work on fixed-size chunks: each chunk is first checked *as a whole*, and if every element is in range, the entire chunk is converted with a plain cast.  Only if a chunk contains a value that might not convert cleanly are its elements converted one at a time, with the normal scalar conversion.
+
+As a result, the output (and any error) is always exactly the same as converting each element individually with the corresponding scalar conversion.
+
+```
+# use conv::RoundToNearest;
+# use conv::bulk::{approx_slice_by, value_slice};
+let src = [1.4f64, -2.5, 300.0];
+let mut dst = [0i16; 3];
+approx_slice_by::<RoundToNearest, _, _>(&src, &mut dst).unwrap();
+assert_eq!(dst, [1, -3, 300]);
+
+let mut dst = [0f32; 3];
+let err = value_slice(&[1i32, 2, 16_777_217], &mut dst).unwrap_err();
+assert_eq!(err.index, 2);
+```
+
+Bulk value conversions are provided for every integer-to-float pair, as well as `f32` → `f32`, `f32` → `f64` and `f64` → `f64`.  Bulk approximations are provided for every float-to-integer pair, with the `DefaultApprox`, `RoundToNearest`, `RoundHalfEven`, `RoundToNegInf`, `RoundToPosInf`, `RoundToZero` and `Wrapping` schemes, and `Saturating` applied to any of the rounding schemes.
+
+There are no bulk integer-to-integer conversions: the scalar range check is already a compare and a cast, and checking a chunk first doesn't make it any faster.  Use [`ValueIntoEach`](../trait.ValueIntoEach.html) or a plain loop instead.
+*/
+
+use {ApproxFrom, ApproxScheme, ConvBounds, DefaultApprox, ValueFrom};
+use errors::ElementError;
+
+/// The number of elements checked at once.
+const CHUNK: usize = 64;
+
+/**
+This trait is used to perform a value conversion from a slice of values into another slice.
+
+The two slices *must* have the same length, or the conversion will panic.  If the conversion of an element fails, the error is returned along with the element's index; every element before it will have been converted, and every element from it onward is left unchanged.
+*/
+pub trait ValueFromSlice<Src>: ValueFrom<Src> + Sized {
+    /// Convert every element of `src`, writing the results to `dst`.
+    fn value_from_slice(src: &[Src], dst: &mut [Self]) -> Result<(), ElementError<Self::Err>>;
+}
+
+/**
+This trait is used to perform an approximate conversion from a slice of values into another slice.
+
+This behaves in the same way as [`ValueFromSlice`](./trait.ValueFromSlice.html), except that each element is converted with `ApproxFrom<Src, Scheme>`.
+*/
+pub trait ApproxFromSlice<Src, Scheme=DefaultApprox>: ApproxFrom<Src, Scheme> + Sized
+where Scheme: ApproxScheme {
+    /// Approximate every element of `src`, writing the results to `dst`.
+    fn approx_from_slice(src: &[Src], dst: &mut [Self]) -> Result<(), ElementError<Self::Err>>;
+}
+
+/**
+Convert every element of `src` with `ValueFrom`, writing the results to `dst`.
+
+See [`ValueFromSlice`](./trait.ValueFromSlice.html).
+*/
+#[inline]
+pub fn value_slice<Src, Dst>(src: &[Src], dst: &mut [Dst]) -> Result<(), ElementError<Dst::Err>>
+where Dst: ValueFromSlice<Src> {
+    Dst::value_from_slice(src, dst)
+}
+
+/**
+Approximate every element of `src` with the default scheme, writing the results to `dst`.
+
+See [`ApproxFromSlice`](./trait.ApproxFromSlice.html).
+*/
+#[inline]
+pub fn approx_slice<Src, Dst>(src: &[Src], dst: &mut [Dst]) -> Result<(), ElementError<Dst::Err>>
+where Dst: ApproxFromSlice<Src> {
+    Dst::approx_from_slice(src, dst)
+}
+
+/**
+Approximate every element of `src` with a specific scheme, writing the results to `dst`.
+
+See [`ApproxFromSlice`](./trait.ApproxFromSlice.html).
+*/
+#[inline]
+pub fn approx_slice_by<Scheme, Src, Dst>(src: &[Src], dst: &mut [Dst]) -> Result<(), ElementError<<Dst as ApproxFrom<Src, Scheme>>::Err>>
+where
+    Dst: ApproxFromSlice<Src, Scheme>,
+    Scheme: ApproxScheme,
+{
+    Dst::approx_from_slice(src, dst)
+}
+
+/**
+Converts `src` into `dst` chunk by chunk.
+
+Each chunk is first checked with `in_range`.  This check is done for the whole chunk without short-circuiting, so that it can be vectorised.  If every element is in range, they are converted with `fast`; otherwise, the chunk is converted one element at a time with `scalar`.
+
+`in_range` must only return `true` for values where `fast` gives the same result as `scalar`.
+*/
+#[inline(always)]
+fn convert_chunked<Src, Dst, E, C, F, S>(
+    src: &[Src],
+    dst: &mut [Dst],
+    in_range: C,
+    fast: F,
+    scalar: S,
+) -> Result<(), ElementError<E>>
+where
+    Src: Copy,
+    C: Fn(Src) -> bool,
+    F: Fn(Src) -> Dst,
+    S: Fn(Src) -> Result<Dst, E>,
+{
+    assert_eq!(src.len(), dst.len(), "source and destination slices must have the same length");
+    for (n, (src, dst)) in src.chunks(CHUNK).zip(dst.chunks_mut(CHUNK)).enumerate() {
+        if src.iter().fold(true, |ok, &v| ok & in_range(v)) {
+            for (d, &s) in dst.iter_mut().zip(src) {
+                *d = fast(s);
+            }
+        } else {
+            convert_scalar(n * CHUNK, src, dst, &scalar)?;
+        }
+    }
+    Ok(())
+}
+
+/**
+Approximates `src` into `dst` chunk by chunk.
+
+This is the same as `convert_chunked`, except that each element is first approximated with `approx`; `in_range` and `fast` are applied to the approximated values.  These are kept in a buffer, so that the approximation is only done once.
+*/
+#[inline(always)]
+fn approx_chunked<Src, A, Dst, E, P, C, F, S>(
+    src: &[Src],
+    dst: &mut [Dst],
+    approx: P,
+    in_range: C,
+    fast: F,
+    scalar: S,
+) -> Result<(), ElementError<E>>
+where
+    Src: Copy,
+    A: Copy + Default,
+    P: Fn(Src) -> A,
+    C: Fn(A) -> bool,
+    F: Fn(A) -> Dst,
+    S: Fn(Src) -> Result<Dst, E>,
+{
+    assert_eq!(src.len(), dst.len(), "source and destination slices must have the same length");
+    let mut approxed = [A::default(); CHUNK];
+    for (n, (src, dst)) in src.chunks(CHUNK).zip(dst.chunks_mut(CHUNK)).enumerate() {
+        let approxed = &mut approxed[..src.len()];
+        let mut ok = true;
+        for (a, &s) in approxed.iter_mut().zip(src) {
+            *a = approx(s);
+            ok &= in_range(*a);
+        }
+        if ok {
+            for (d, &a) in dst.iter_mut().zip(approxed.iter()) {
+                *d = fast(a);
+            }
+        } else {
+            convert_scalar(n * CHUNK, src, dst, &scalar)?;
+        }
+    }
+    Ok(())
+}
+
+/// Converts a single chunk one element at a time, stopping at the first failure.
+#[inline(always)]
+fn convert_scalar<Src, Dst, E, S>(offset: usize, src: &[Src], dst: &mut [Dst], scalar: &S) -> Result<(), ElementError<E>>
+where
+    Src: Copy,
+    S: Fn(Src) -> Result<Dst, E>,
+{
+    for (i, (d, &s)) in dst.iter_mut().zip(src).enumerate() {
+        match scalar(s) {
+            Ok(v) => *d = v,
+            Err(error) => return Err(ElementError { index: offset + i, error }),
+        }
+    }
+    Ok(())
+}
+
+macro_rules! value_slice {
+    ($($src:ident)* => $dsts:tt) => {
+        $(value_slice! { @ $src => $dsts })*
+    };
+
+    (@ $src:ident => ($($dst:ident)*)) => {
+        $(
+            impl ValueFromSlice<$src> for $dst {
+                #[inline]
+                fn value_from_slice(src: &[$src], dst: &mut [$dst]) -> Result<(), ElementError<Self::Err>> {
+                    let lo = <$dst as ConvBounds<$src>>::min_src();
+                    let hi = <$dst as ConvBounds<$src>>::max_src();
+                    convert_chunked(src, dst,
+                        |v| (lo <= v) & (v <= hi),
+                        |v| v as $dst,
+                        <$dst as ValueFrom<$src>>::value_from)
+                }
+            }
+        )*
+    };
+}
+
+value_slice! {
+    i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize
+    => (f32 f64)
+}
+
+value_slice! { f32 => (f32 f64) }
+value_slice! { f64 => (f64) }
+
+/*
+The standard rounding methods are (on most targets) calls into the platform's maths library, which prevents vectorisation.  These are branch-free replacements which produce the same value, up to the sign of a zero result, which doesn't matter once the result has been cast to an integer.
+
+They are all built on rounding to nearest, ties to even, which is what adding and then subtracting `LIMIT` (the smallest magnitude at which every value is an integer) does under the default floating point environment.  Values at or beyond `LIMIT` are already integral, and are passed through unchanged (as are infinities and NaN).
+*/
+trait FastRound: Sized {
+    fn fast_round_ties_even(self) -> Self;
+    fn fast_trunc(self) -> Self;
+    fn fast_floor(self) -> Self;
+    fn fast_ceil(self) -> Self;
+    fn fast_round(self) -> Self;
+}
+
+macro_rules! fast_round {
+    ($($fty:ident, $limit:expr;)*) => {
+        $(
+            impl FastRound for $fty {
+                #[inline(always)]
+                fn fast_round_ties_even(self) -> $fty {
+                    let mag = self.abs();
+                    if mag < $limit { ((mag + $limit) - $limit).copysign(self) } else { self }
+                }
+
+                #[inline(always)]
+                fn fast_trunc(self) -> $fty {
+                    let r = self.fast_round_ties_even();
+                    if r.abs() > self.abs() { r - (1.0 as $fty).copysign(self) } else { r }
+                }
+
+                #[inline(always)]
+                fn fast_floor(self) -> $fty {
+                    let r = self.fast_round_ties_even();
+                    if r > self { r - 1.0 } else { r }
+                }
+
+                #[inline(always)]
+                fn fast_ceil(self) -> $fty {
+                    let r = self.fast_round_ties_even();
+                    if r < self { r + 1.0 } else { r }
+                }
+
+                #[inline(always)]
+                fn fast_round(self) -> $fty {
+                    // Ties that went towards zero need to go the other way.
+                    let r = self.fast_round_ties_even();
+                    if self - r == (0.5 as $fty).copysign(self) { r + (1.0 as $fty).copysign(self) } else { r }
+                }
+            }
+        )*
+    };
+}
+
+fast_round! {
+    f32, 8_388_608.0;
+    f64, 4_503_599_627_370_496.0;
+}
+
+/*
+The approximation is applied before the range check, exactly as in the scalar conversion.  The upper bound is checked against both the destination's maximum and, exclusively, against `MAX + 1`, since the maximum of the wider integer types is not exactly representable, and rounds *up* to that power of two.  `MAX + 1` is computed as `(MAX / 2 + 1) * 2`, the same way as in the scalar conversions, so that it is exact (or infinite) rather than rounded.
+*/
+macro_rules! approx_slice {
+    ($($src:ident)* => $dsts:tt) => {
+        $(approx_slice! { @ $src => $dsts })*
+    };
+
+    (@ $src:ident => ($($dst:ident)*)) => {
+        $(
+            approx_slice! { @@ $src, $dst, ::DefaultApprox, |s| s }
+            approx_slice! { @@ $src, $dst, ::RoundToNearest, |s| s.fast_round() }
+            approx_slice! { @@ $src, $dst, ::RoundHalfEven, |s| s.fast_round_ties_even() }
+            approx_slice! { @@ $src, $dst, ::RoundToNegInf, |s| s.fast_floor() }
+            approx_slice! { @@ $src, $dst, ::RoundToPosInf, |s| s.fast_ceil() }
+            approx_slice! { @@ $src, $dst, ::RoundToZero, |s| s.fast_trunc() }
+            approx_slice! { @@ $src, $dst, ::Wrapping, |s| s.fast_trunc() }
+            approx_slice! { @@ $src, $dst, ::Saturating<::DefaultApprox>, |s| s }
+            approx_slice! { @@ $src, $dst, ::Saturating<::RoundToNearest>, |s| s.fast_round() }
+            approx_slice! { @@ $src, $dst, ::Saturating<::RoundHalfEven>, |s| s.fast_round_ties_even() }
+            approx_slice! { @@ $src, $dst, ::Saturating<::RoundToNegInf>, |s| s.fast_floor() }
+            approx_slice! { @@ $src, $dst, ::Saturating<::RoundToPosInf>, |s| s.fast_ceil() }
+            approx_slice! { @@ $src, $dst, ::Saturating<::RoundToZero>, |s| s.fast_trunc() }
+        )*
+    };
+
+    (@@ $src:ident, $dst:ident, $scheme:ty, |$s:ident| $approx:expr) => {
+        impl ApproxFromSlice<$src, $scheme> for $dst {
+            #[inline]
+            fn approx_from_slice(src: &[$src], dst: &mut [$dst]) -> Result<(), ElementError<Self::Err>> {
+                let lo = <$dst>::MIN as $src;
+                let hi = <$dst>::MAX as $src;
+                let hi_excl = (<$dst>::MAX / 2 + 1) as $src * 2.0;
+                approx_chunked(src, dst,
+                    |$s: $src| $approx,
+                    |a| (lo <= a) & (a <= hi) & (a < hi_excl),
+                    |a| a as $dst,
+                    <$dst as ApproxFrom<$src, $scheme>>::approx_from)
+            }
+        }
+    };
+}
+
+approx_slice! {
+    f32 f64 => (i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize)
+}
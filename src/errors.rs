@@ -1,13 +1,13 @@
 /*!
 This module defines the various error types that can be produced by a failed conversion.
 
-In addition, it also defines some extension traits to make working with failable conversions more ergonomic (see the `Unwrap*` traits).
+In addition, it also defines some extension traits to make working with failable conversions more ergonomic (see the `Unwrap*` traits, and `WithDetail` for producing descriptive errors).
 */
 
-use std::any::Any;
+use std::any::{type_name, Any};
 use std::error::Error;
 use std::fmt::{self, Debug, Display};
-use misc::{Saturated, InvalidSentinel, SignedInfinity};
+use misc::{Saturated, InvalidSentinel, SignedInfinity, DescribeBounds};
 
 macro_rules! Desc {
     (
@@ -237,6 +237,7 @@ custom_derive!{
             NegOverflow => "conversion resulted in negative overflow",
             PosOverflow => "conversion resulted in positive overflow",
             Unrepresentable => "could not convert unrepresentable value",
+            Zero => "conversion target does not support zero",
         ),
         FromName(Unrepresentable),
         FromName(NegOverflow),
@@ -252,6 +253,16 @@ custom_derive!{
 
         /// Input was not representable in the target type.
         Unrepresentable(T),
+
+        /// Input was zero, which the target type could not represent.
+        Zero(T),
+    }
+}
+
+impl<T> From<ZeroError<T>> for GeneralError<T> {
+    #[inline]
+    fn from(e: ZeroError<T>) -> GeneralError<T> {
+        GeneralError::Zero(e.into_inner())
     }
 }
 
@@ -281,13 +292,14 @@ custom_derive! {
             NegOverflow => "conversion resulted in negative overflow",
             PosOverflow => "conversion resulted in positive overflow",
             Unrepresentable => "could not convert unrepresentable value",
+            Zero => "conversion target does not support zero",
         ),
         FromName(Unrepresentable<T>),
         FromName(NegOverflow<T>),
         FromName(PosOverflow<T>),
         FromRemap(RangeErrorKind(NegOverflow, PosOverflow)),
         FromRemap(RangeError<T>(NegOverflow, PosOverflow)),
-        FromRemap(GeneralError<T>(NegOverflow, PosOverflow, Unrepresentable))
+        FromRemap(GeneralError<T>(NegOverflow, PosOverflow, Unrepresentable, Zero))
     )]
     pub enum GeneralErrorKind {
         /// Input was too negative for the target type.
@@ -298,6 +310,16 @@ custom_derive! {
 
         /// Input was not representable in the target type.
         Unrepresentable,
+
+        /// Input was zero, which the target type could not represent.
+        Zero,
+    }
+}
+
+impl<T> From<ZeroError<T>> for GeneralErrorKind {
+    #[inline]
+    fn from(_: ZeroError<T>) -> GeneralErrorKind {
+        GeneralErrorKind::Zero
     }
 }
 
@@ -364,6 +386,16 @@ custom_derive! {
     pub struct PosOverflow<T>(pub T);
 }
 
+custom_derive! {
+    /// Indicates that the conversion failed because the value was zero, and the target type cannot represent zero.
+    #[derive(
+        Copy, Clone, Eq, PartialEq, Ord, PartialOrd,
+        IntoInner, DummyDebug, FromNoError,
+        Desc("conversion target does not support zero")
+    )]
+    pub struct ZeroError<T>(pub T);
+}
+
 custom_derive! {
     /**
     Indicates that a conversion from a floating point type failed.
@@ -441,6 +473,421 @@ custom_derive! {
     }
 }
 
+/**
+Indicates that the conversion of a single element of a collection failed.
+
+The element's own conversion error (and thus the element's value) is retained in `error`.
+*/
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub struct ElementError<E> {
+    /// The index of the element that could not be converted.
+    pub index: usize,
+
+    /// The error produced by converting the element.
+    pub error: E,
+}
+
+impl<E> Display for ElementError<E> where E: Display {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(fmt, "conversion of element {} failed: {}", self.index, self.error)
+    }
+}
+
+impl<E> Error for ElementError<E> where E: Error + Any {
+    fn description(&self) -> &str {
+        "conversion of an element failed"
+    }
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/**
+Indicates that the conversion of one or more elements of a collection failed.
+
+The failures are listed in ascending order of index.
+*/
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub struct ElementErrors<E>(pub Vec<ElementError<E>>);
+
+impl<E> ElementErrors<E> {
+    /// Returns the indices of the elements that could not be converted.
+    pub fn indices(&self) -> Vec<usize> {
+        self.0.iter().map(|e| e.index).collect()
+    }
+
+    /// Discard the error wrapper, returning the individual element failures.
+    pub fn into_inner(self) -> Vec<ElementError<E>> {
+        self.0
+    }
+}
+
+impl<E> Display for ElementErrors<E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(fmt, "conversion of {} element(s) failed", self.0.len())
+    }
+}
+
+impl<E> Error for ElementErrors<E> where E: Debug {
+    fn description(&self) -> &str {
+        "conversion of one or more elements failed"
+    }
+}
+
+/**
+Indicates that the conversion of a single field of a tuple failed.
+
+The variant identifies the field, and carries the error produced by converting that field.  Type parameters for fields beyond the arity of the tuple are filled in with `NoError`.
+*/
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub enum FieldError<
+    E0, E1=NoError, E2=NoError, E3=NoError, E4=NoError, E5=NoError,
+    E6=NoError, E7=NoError, E8=NoError, E9=NoError, E10=NoError, E11=NoError,
+> {
+    /// Field 0 could not be converted.
+    Field0(E0),
+
+    /// Field 1 could not be converted.
+    Field1(E1),
+
+    /// Field 2 could not be converted.
+    Field2(E2),
+
+    /// Field 3 could not be converted.
+    Field3(E3),
+
+    /// Field 4 could not be converted.
+    Field4(E4),
+
+    /// Field 5 could not be converted.
+    Field5(E5),
+
+    /// Field 6 could not be converted.
+    Field6(E6),
+
+    /// Field 7 could not be converted.
+    Field7(E7),
+
+    /// Field 8 could not be converted.
+    Field8(E8),
+
+    /// Field 9 could not be converted.
+    Field9(E9),
+
+    /// Field 10 could not be converted.
+    Field10(E10),
+
+    /// Field 11 could not be converted.
+    Field11(E11),
+}
+
+macro_rules! field_error_impls {
+    ($($idx:tt $var:ident $ety:ident),*) => {
+        impl<$($ety),*> FieldError<$($ety),*> {
+            /// Returns the index of the field that could not be converted.
+            pub fn field(&self) -> usize {
+                match *self {
+                    $(FieldError::$var(..) => $idx,)*
+                }
+            }
+        }
+
+        impl<$($ety),*> Display for FieldError<$($ety),*> where $($ety: Display),* {
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+                match *self {
+                    $(FieldError::$var(ref e) => write!(fmt, "conversion of field {} failed: {}", $idx, e),)*
+                }
+            }
+        }
+
+        impl<$($ety),*> Error for FieldError<$($ety),*> where $($ety: Error),* {
+            fn description(&self) -> &str {
+                "conversion of a field failed"
+            }
+        }
+
+        impl<$($ety),*> From<FieldError<$($ety),*>> for GeneralErrorKind where $($ety: Into<GeneralErrorKind>),* {
+            #[inline]
+            fn from(e: FieldError<$($ety),*>) -> GeneralErrorKind {
+                match e {
+                    $(FieldError::$var(e) => e.into(),)*
+                }
+            }
+        }
+    };
+}
+
+field_error_impls! {
+    0 Field0 E0,
+    1 Field1 E1,
+    2 Field2 E2,
+    3 Field3 E3,
+    4 Field4 E4,
+    5 Field5 E5,
+    6 Field6 E6,
+    7 Field7 E7,
+    8 Field8 E8,
+    9 Field9 E9,
+    10 Field10 E10,
+    11 Field11 E11
+}
+
+/**
+Indicates that the conversion of a named field of a struct failed.
+
+Since the fields of a struct can have any number of different types, the error produced by converting the field is turned into a `GeneralError` whose payload is boxed.  The value that could not be converted can be recovered with [`value`](#method.value), given its type.  This does mean that the types of the fields being converted must be `'static`, `Send` and `Sync`.
+
+This is the error produced by the conversions generated by `#[derive(Fieldwise)]` in the [`conv_derive`](https://crates.io/crates/conv_derive) crate.
+
+```
+# use conv::*;
+# use conv::errors::NamedFieldError;
+let e = NamedFieldError::convert("length", 70000i32, u16::value_from).unwrap_err();
+assert_eq!(e.field, "length");
+assert_eq!(e.kind(), GeneralErrorKind::PosOverflow);
+assert_eq!(e.value::<i32>(), Some(&70000));
+```
+*/
+#[derive(Debug)]
+pub struct NamedFieldError {
+    /// The name of the field that could not be converted.
+    pub field: &'static str,
+
+    /// The error produced by converting the field, holding the value that could not be converted.
+    pub error: GeneralError<Box<dyn Any + Send + Sync>>,
+}
+
+impl NamedFieldError {
+    /// Creates an error for the field named `field`.
+    #[inline]
+    pub fn new<T, E>(field: &'static str, error: E) -> NamedFieldError
+    where T: Any + Send + Sync, E: Into<GeneralError<T>> {
+        let boxed = |v: T| -> Box<dyn Any + Send + Sync> { Box::new(v) };
+        let error = match error.into() {
+            GeneralError::NegOverflow(v) => GeneralError::NegOverflow(boxed(v)),
+            GeneralError::PosOverflow(v) => GeneralError::PosOverflow(boxed(v)),
+            GeneralError::Unrepresentable(v) => GeneralError::Unrepresentable(boxed(v)),
+            GeneralError::Zero(v) => GeneralError::Zero(boxed(v)),
+        };
+        NamedFieldError { field, error }
+    }
+
+    /**
+    Converts `value`, the contents of the field named `field`, with `conversion`.
+
+    Any error is turned into a `NamedFieldError` for that field.
+    */
+    #[inline]
+    pub fn convert<Src, Dst, E, F>(field: &'static str, value: Src, conversion: F) -> Result<Dst, NamedFieldError>
+    where Src: Any + Send + Sync, E: Into<GeneralError<Src>>, F: FnOnce(Src) -> Result<Dst, E> {
+        conversion(value).map_err(|e| NamedFieldError::new(field, e))
+    }
+
+    /// Returns the kind of error, without the value.
+    #[inline]
+    pub fn kind(&self) -> GeneralErrorKind {
+        match self.error {
+            GeneralError::NegOverflow(_) => GeneralErrorKind::NegOverflow,
+            GeneralError::PosOverflow(_) => GeneralErrorKind::PosOverflow,
+            GeneralError::Unrepresentable(_) => GeneralErrorKind::Unrepresentable,
+            GeneralError::Zero(_) => GeneralErrorKind::Zero,
+        }
+    }
+
+    /// Returns the value that could not be converted, if it is a `T`.
+    #[inline]
+    pub fn value<T>(&self) -> Option<&T> where T: Any {
+        match self.error {
+            GeneralError::NegOverflow(ref v)
+            | GeneralError::PosOverflow(ref v)
+            | GeneralError::Unrepresentable(ref v)
+            | GeneralError::Zero(ref v) => v.downcast_ref(),
+        }
+    }
+}
+
+impl Display for NamedFieldError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(fmt, "conversion of field `{}` failed: {}", self.field, self.error)
+    }
+}
+
+impl Error for NamedFieldError {
+    fn description(&self) -> &str {
+        "conversion of a field failed"
+    }
+}
+
+impl From<NamedFieldError> for GeneralErrorKind {
+    #[inline]
+    fn from(e: NamedFieldError) -> GeneralErrorKind {
+        e.kind()
+    }
+}
+
+/**
+A conversion error, along with a description of the conversion that failed.
+
+The lightweight error types only describe *what* went wrong, which keeps them cheap to produce and to match on.  This type additionally records the names of the source and target types, the value being converted, and the bound it violated, so that it can be displayed as, for example, "300i32 exceeds u8::MAX (255)".
+
+It is usually created with the [`WithDetail`](./trait.WithDetail.html) extension trait.
+
+```
+# use conv::*;
+let e = u8::value_from(300i32).with_detail().unwrap_err();
+assert_eq!(e.to_string(), "300i32 exceeds u8::MAX (255)");
+assert_eq!(e.kind(), GeneralErrorKind::PosOverflow);
+```
+*/
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct DetailedError {
+    kind: GeneralErrorKind,
+    src_type: &'static str,
+    dst_type: &'static str,
+    value: Option<String>,
+    bound: Option<String>,
+    bound_is_limit: bool,
+}
+
+impl DetailedError {
+    /// Describes a failed conversion of a `Src` into a `Dst`, including the value that could not be converted.
+    pub fn new<Src, Dst>(error: GeneralError<Src>) -> DetailedError
+    where Src: Display, Dst: DescribeBounds<Src> {
+        let value = match error {
+            GeneralError::NegOverflow(ref v)
+            | GeneralError::PosOverflow(ref v)
+            | GeneralError::Unrepresentable(ref v)
+            | GeneralError::Zero(ref v) => compact_float(type_name::<Src>(), v.to_string()),
+        };
+        let mut e = DetailedError::without_value::<Src, Dst>(error.into());
+        e.value = Some(value);
+        e
+    }
+
+    /// Describes a failed conversion of a `Src` into a `Dst`, for source types which cannot be displayed.
+    pub fn without_value<Src, Dst>(kind: GeneralErrorKind) -> DetailedError
+    where Dst: DescribeBounds<Src> {
+        let bound = match kind {
+            GeneralErrorKind::NegOverflow => Dst::describe_min(),
+            GeneralErrorKind::PosOverflow => Dst::describe_max(),
+            GeneralErrorKind::Unrepresentable | GeneralErrorKind::Zero => None,
+        };
+        DetailedError {
+            kind,
+            src_type: type_name::<Src>(),
+            dst_type: type_name::<Dst>(),
+            value: None,
+            bound,
+            bound_is_limit: Dst::bounds_are_limits(),
+        }
+    }
+
+    /// Returns the kind of failure.
+    pub fn kind(&self) -> GeneralErrorKind {
+        self.kind
+    }
+
+    /// Returns the full name of the source type.
+    pub fn src_type(&self) -> &'static str {
+        self.src_type
+    }
+
+    /// Returns the full name of the target type.
+    pub fn dst_type(&self) -> &'static str {
+        self.dst_type
+    }
+
+    /// Returns the value that could not be converted, if it was recorded.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_ref().map(|s| &s[..])
+    }
+
+    /// Returns the bound that the value violated, if there is one.
+    pub fn bound(&self) -> Option<&str> {
+        self.bound.as_ref().map(|s| &s[..])
+    }
+}
+
+impl Display for DetailedError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let src = short_type_name(self.src_type);
+        let dst = short_type_name(self.dst_type);
+
+        // Numbers are written like literals, with the type as a suffix.
+        let value = match self.value {
+            Some(ref v) if is_numeric_type(&src) && v.ends_with(|c: char| c.is_ascii_digit()) => format!("{}{}", v, src),
+            Some(ref v) => format!("{} value {}", src, v),
+            None => format!("{} value", src),
+        };
+
+        match (self.kind, self.bound.as_ref()) {
+            (GeneralErrorKind::NegOverflow, Some(b)) if self.bound_is_limit => write!(fmt, "{} is below {}::MIN ({})", value, dst, b),
+            (GeneralErrorKind::NegOverflow, Some(b)) => write!(fmt, "{} is below the minimum for {} ({})", value, dst, b),
+            (GeneralErrorKind::NegOverflow, None) => write!(fmt, "{} is below the minimum of {}", value, dst),
+            (GeneralErrorKind::PosOverflow, Some(b)) if self.bound_is_limit => write!(fmt, "{} exceeds {}::MAX ({})", value, dst, b),
+            (GeneralErrorKind::PosOverflow, Some(b)) => write!(fmt, "{} exceeds the maximum for {} ({})", value, dst, b),
+            (GeneralErrorKind::PosOverflow, None) => write!(fmt, "{} exceeds the maximum of {}", value, dst),
+            (GeneralErrorKind::Unrepresentable, _) => write!(fmt, "{} cannot be represented as {}", value, dst),
+            (GeneralErrorKind::Zero, _) => write!(fmt, "{} cannot be represented as {}, which does not support zero", value, dst),
+        }
+    }
+}
+
+impl Error for DetailedError {
+    fn description(&self) -> &str {
+        "conversion failed"
+    }
+}
+
+impl From<DetailedError> for GeneralErrorKind {
+    #[inline]
+    fn from(e: DetailedError) -> GeneralErrorKind {
+        e.kind
+    }
+}
+
+/// Strips the module paths from a type name, turning `alloc::string::String` into `String`.
+fn short_type_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut segment_start = 0;
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            out.truncate(segment_start);
+        } else {
+            out.push(c);
+            if !(c.is_alphanumeric() || c == '_') {
+                segment_start = out.len();
+            }
+        }
+    }
+    out
+}
+
+/// Rewrites a displayed float in exponent form, if that is shorter; large floats otherwise display every digit.
+fn compact_float(type_name: &str, value: String) -> String {
+    let exp = match type_name {
+        "f32" => value.parse::<f32>().ok().map(|f| format!("{:e}", f)),
+        "f64" => value.parse::<f64>().ok().map(|f| format!("{:e}", f)),
+        _ => None,
+    };
+    match exp {
+        Some(exp) if exp.len() < value.len() => exp,
+        _ => value,
+    }
+}
+
+fn is_numeric_type(name: &str) -> bool {
+    const NUMERIC: &[&str] = &[
+        "i8", "i16", "i32", "i64", "i128", "isize",
+        "u8", "u16", "u32", "u64", "u128", "usize",
+        "f32", "f64",
+    ];
+    NUMERIC.contains(&name)
+}
+
 /**
 Saturates a `Result`.
 */
@@ -565,6 +1012,21 @@ pub trait UnwrapOrSaturate {
     fn unwrap_or_saturate(self) -> Self::Output;
 }
 
+/**
+Attach a description of the failed conversion to an error.
+
+The source type is that of the value carried by the error, and the target type is that of the successful result.
+*/
+pub trait WithDetail<Src> {
+    /// The result of attaching the description.
+    type Output;
+
+    /**
+    Replaces the error with a `DetailedError`, which records the value that could not be converted, the source and target types, and the violated bound.
+    */
+    fn with_detail(self) -> Self::Output;
+}
+
 impl<T, E> UnwrapOrInf for Result<T, E>
 where T: SignedInfinity, E: Into<RangeErrorKind> {
     type Output = T;
@@ -573,8 +1035,8 @@ where T: SignedInfinity, E: Into<RangeErrorKind> {
         use self::RangeErrorKind::*;
         match self.map_err(Into::into) {
             Ok(v) => v,
-            Err(NegOverflow(..)) => T::neg_infinity(),
-            Err(PosOverflow(..)) => T::pos_infinity(),
+            Err(NegOverflow) => T::neg_infinity(),
+            Err(PosOverflow) => T::pos_infinity(),
         }
     }
 }
@@ -599,8 +1061,17 @@ where T: Saturated, E: Into<RangeErrorKind> {
         use self::RangeErrorKind::*;
         match self.map_err(Into::into) {
             Ok(v) => v,
-            Err(NegOverflow(..)) => T::saturated_min(),
-            Err(PosOverflow(..)) => T::saturated_max(),
+            Err(NegOverflow) => T::saturated_min(),
+            Err(PosOverflow) => T::saturated_max(),
         }
     }
 }
+
+impl<T, E, Src> WithDetail<Src> for Result<T, E>
+where T: DescribeBounds<Src>, E: Into<GeneralError<Src>>, Src: Display {
+    type Output = Result<T, DetailedError>;
+    #[inline]
+    fn with_detail(self) -> Result<T, DetailedError> {
+        self.map_err(|e| DetailedError::new::<Src, T>(e.into()))
+    }
+}
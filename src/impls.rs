@@ -6,6 +6,38 @@ macro_rules! min_of {
     ($name:ident) => { ::std::$name::MIN };
 }
 
+/**
+Invokes `$callback! { $item=> $others... }` once for each item, where `$others` is every *other* item.
+
+This is used to implement conversions between every distinct pair of a family of types, skipping the reflexive pairs that are already covered by blanket implementations.
+*/
+macro_rules! distinct_pairs {
+    (@rot $callback:ident [$($before:tt)*]) => {};
+
+    (@rot $callback:ident [$($before:tt)*] $cur:tt $($after:tt)*) => {
+        $callback! { $cur=> $($before)* $($after)* }
+        distinct_pairs! { @rot $callback [$($before)* $cur] $($after)* }
+    };
+
+    ($callback:ident! $($item:tt)*) => {
+        distinct_pairs! { @rot $callback [] $($item)* }
+    };
+}
+
+macro_rules! conv_bounds {
+    (($($attrs:tt)*), $src:ty, $dst:ty, $min:expr, $max:expr) => {
+        as_item! {
+            $($attrs)*
+            impl ::ConvBounds<$src> for $dst {
+                #[inline]
+                fn min_src() -> $src { $min }
+                #[inline]
+                fn max_src() -> $src { $max }
+            }
+        }
+    };
+}
+
 macro_rules! approx_blind {
     (($($attrs:tt)*), $src:ty, $dst:ty, $scheme:ty) => {
         as_item! {
@@ -32,7 +64,7 @@ macro_rules! approx_z_to_dmax {
                     if !(0 <= src) {
                         return Err(::errors::RangeError::NegOverflow(src));
                     }
-                    if !(src <= max_of!($dst) as $src) {
+                    if !(src as u128 <= max_of!($dst) as u128) {
                         return Err(::errors::RangeError::PosOverflow(src));
                     }
                     Ok(src as $dst)
@@ -99,6 +131,140 @@ macro_rules! approx_z_up {
     };
 }
 
+macro_rules! approx_to_dfinite {
+    (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty) => {
+        as_item! {
+            $($attrs)*
+            impl ::ApproxFrom<$src, $scheme> for $dst {
+                type Err = ::errors::PosOverflow<$src>;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    let approx = src as $dst;
+                    if !approx.is_finite() {
+                        return Err(::errors::PosOverflow(src));
+                    }
+                    Ok(approx)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! approx_saturating {
+    (($($attrs:tt)*), $src:ty, $dst:ty) => {
+        as_item! {
+            $($attrs)*
+            impl<Round> ::ApproxFrom<$src, ::Saturating<Round>> for $dst
+            where Round: ::ApproxScheme {
+                type Err = ::errors::NoError;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    use ::errors::UnwrapOrSaturate;
+                    Ok(<$dst as ::ValueFrom<$src>>::value_from(src).unwrap_or_saturate())
+                }
+            }
+        }
+    };
+}
+
+macro_rules! approx_saturating_no_nan {
+    (($($attrs:tt)*), $src:ty, $dst:ty) => {
+        as_item! {
+            $($attrs)*
+            impl<Round> ::ApproxFrom<$src, ::Saturating<Round>> for $dst
+            where
+                Round: ::ApproxScheme,
+                $dst: ::ApproxFrom<$src, Round, Err=::errors::FloatError<$src>>,
+            {
+                type Err = ::errors::Unrepresentable<$src>;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    use ::errors::Saturate;
+                    <$dst as ::ApproxFrom<$src, Round>>::approx_from(src).saturate()
+                }
+            }
+        }
+    };
+}
+
+macro_rules! approx_wrapping_no_nan {
+    (($($attrs:tt)*), $src:ty, $dst:ty) => {
+        as_item! {
+            $($attrs)*
+            impl ::ApproxFrom<$src, ::Wrapping> for $dst {
+                type Err = ::errors::FloatError<$src>;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    if src.is_nan() {
+                        return Err(::errors::FloatError::NotANumber(src));
+                    }
+                    if src.is_infinite() && src < 0.0 {
+                        return Err(::errors::FloatError::NegOverflow(src));
+                    }
+                    if src.is_infinite() {
+                        return Err(::errors::FloatError::PosOverflow(src));
+                    }
+                    Ok(::impls::WrapTrunc::wrap_trunc(src) as $dst)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! approx_int_to_float {
+    (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty, |$neg:ident| $round:expr) => {
+        as_item! {
+            $($attrs)*
+            impl ::ApproxFrom<$src, $scheme> for $dst {
+                type Err = ::errors::NoError;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    use ::impls::IntRound::*;
+                    let ($neg, mag) = ::impls::SignMag::sign_mag(src);
+                    let (mant, exp) = ::impls::round_int_mag(mag, ::std::$dst::MANTISSA_DIGITS, $round);
+                    let approx = mant as $dst * (1u128 << exp) as $dst;
+                    Ok(if $neg { -approx } else { approx })
+                }
+            }
+        }
+    };
+
+    (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty, |$neg:ident| $round:expr, +inf) => {
+        as_item! {
+            $($attrs)*
+            impl ::ApproxFrom<$src, $scheme> for $dst {
+                type Err = ::errors::PosOverflow<$src>;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    use ::impls::IntRound::*;
+                    let ($neg, mag) = ::impls::SignMag::sign_mag(src);
+                    let (mant, exp) = ::impls::round_int_mag(mag, ::std::$dst::MANTISSA_DIGITS, $round);
+                    let approx = mant as $dst * (1u128 << exp) as $dst;
+                    if !approx.is_finite() {
+                        return Err(::errors::PosOverflow(src));
+                    }
+                    Ok(if $neg { -approx } else { approx })
+                }
+            }
+        }
+    };
+}
+
+macro_rules! approx_int_to_float_rounded {
+    (($($attrs:tt)*), $src:ty, $dst:ident $(, +$inf:ident)*) => {
+        approx_int_to_float! { ($($attrs)*), $src, $dst, ::RoundToNearest,
+            |_neg| HalfAway $(, +$inf)* }
+        approx_int_to_float! { ($($attrs)*), $src, $dst, ::RoundHalfEven,
+            |_neg| HalfEven $(, +$inf)* }
+        approx_int_to_float! { ($($attrs)*), $src, $dst, ::RoundToZero,
+            |_neg| Trunc }
+        approx_int_to_float! { ($($attrs)*), $src, $dst, ::RoundToNegInf,
+            |neg| if neg { Away } else { Trunc } }
+        approx_int_to_float! { ($($attrs)*), $src, $dst, ::RoundToPosInf,
+            |neg| if neg { Trunc } else { Away } $(, +$inf)* }
+    };
+}
+
 macro_rules! approx_dmin_to_dmax_no_nan {
     (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty) => {
         approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, $scheme, approx: |s| s }
@@ -128,6 +294,48 @@ macro_rules! approx_dmin_to_dmax_no_nan {
     };
 }
 
+/**
+Like `approx_dmin_to_dmax_no_nan`, for destinations whose maximum is not exactly representable in the source float type.
+
+In that case, `max_of!($dst) as $src` rounds up to 2<sup>N</sup> (or even to infinity), so an inclusive check against it would accept values that do not fit.  Instead, non-finite values are rejected up front, and the upper bound is checked exclusively against `MAX + 1`, which is a power of two.
+*/
+macro_rules! approx_dmin_to_pow2_no_nan {
+    (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty) => {
+        approx_dmin_to_pow2_no_nan! { ($($attrs)*), $src, $dst, $scheme, approx: |s| s }
+    };
+
+    (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty, approx: |$src_name:ident| $conv:expr) => {
+        as_item! {
+            $($attrs)*
+            impl ::ApproxFrom<$src, $scheme> for $dst {
+                type Err = ::errors::FloatError<$src>;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    if src.is_nan() {
+                        return Err(::errors::FloatError::NotANumber(src));
+                    }
+                    if src.is_infinite() {
+                        return Err(if src < 0.0 {
+                            ::errors::FloatError::NegOverflow(src)
+                        } else {
+                            ::errors::FloatError::PosOverflow(src)
+                        });
+                    }
+                    let approx = { let $src_name = src; $conv };
+                    if approx < min_of!($dst) as $src {
+                        return Err(::errors::FloatError::NegOverflow(src));
+                    }
+                    // `MAX / 2 + 1` is exact as a float, and doubling it gives `MAX + 1` (or infinity, which is still a correct bound for finite values).
+                    if approx >= (max_of!($dst) / 2 + 1) as $src * 2.0 {
+                        return Err(::errors::FloatError::PosOverflow(src));
+                    }
+                    Ok(approx as $dst)
+                }
+            }
+        }
+    };
+}
+
 macro_rules! num_conv {
     (@ $src:ty=> $(,)*) => {};
 
@@ -145,14 +353,20 @@ macro_rules! num_conv {
     (@ $src:ty=> w+  $($tail:tt)*) => { num_conv! { @ $src=> () w+  $($tail)* } };
     (@ $src:ty=> w   $($tail:tt)*) => { num_conv! { @ $src=> () w   $($tail)* } };
     (@ $src:ty=> aW  $($tail:tt)*) => { num_conv! { @ $src=> () aW  $($tail)* } };
+    (@ $src:ty=> wf  $($tail:tt)*) => { num_conv! { @ $src=> () wf  $($tail)* } };
     (@ $src:ty=> nf  $($tail:tt)*) => { num_conv! { @ $src=> () nf  $($tail)* } };
+    (@ $src:ty=> nfo $($tail:tt)*) => { num_conv! { @ $src=> () nfo $($tail)* } };
     (@ $src:ty=> fan $($tail:tt)*) => { num_conv! { @ $src=> () fan $($tail)* } };
+    (@ $src:ty=> fanx $($tail:tt)*) => { num_conv! { @ $src=> () fanx $($tail)* } };
 
     // Exact conversion
     (@ $src:ty=> ($($attrs:tt)*) e $dst:ty, $($tail:tt)*) => {
         as_item! {
             approx_blind! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_blind! { ($($attrs)*), $src, $dst, ::Wrapping }
+            approx_saturating! { ($($attrs)*), $src, $dst }
+
+            conv_bounds! { ($($attrs)*), $src, $dst, <$src>::MIN, <$src>::MAX }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -166,11 +380,15 @@ macro_rules! num_conv {
         num_conv! { @ $src=> $($tail)* }
     };
 
-    // Narrowing a signed type *into* an unsigned type where the destination type's maximum value is representable by the source type.
+    // Narrowing a signed type *into* an unsigned type.  The destination type's maximum value need not be representable by the source type (*e.g.* `i64` → `usize` on 64-bit targets), so non-negative values are compared as `u128`.
     (@ $src:ty=> ($($attrs:tt)*) n+ $dst:ident, $($tail:tt)*) => {
         as_item! {
             approx_z_to_dmax! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_blind! { ($($attrs)*), $src, $dst, ::Wrapping }
+            approx_saturating! { ($($attrs)*), $src, $dst }
+
+            conv_bounds! { ($($attrs)*), $src, $dst, 0,
+                if max_of!($dst) as u128 <= <$src>::MAX as u128 { max_of!($dst) as $src } else { <$src>::MAX } }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -180,7 +398,7 @@ macro_rules! num_conv {
                     if !(0 <= src) {
                         return Err(::errors::RangeError::NegOverflow(src));
                     }
-                    if !(src <= max_of!($dst) as $src) {
+                    if !(src as u128 <= max_of!($dst) as u128) {
                         return Err(::errors::RangeError::PosOverflow(src));
                     }
                     Ok(src as $dst)
@@ -195,6 +413,9 @@ macro_rules! num_conv {
         as_item! {
             approx_to_dmax! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_blind! { ($($attrs)*), $src, $dst, ::Wrapping }
+            approx_saturating! { ($($attrs)*), $src, $dst }
+
+            conv_bounds! { ($($attrs)*), $src, $dst, <$src>::MIN, max_of!($dst) as $src }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -216,6 +437,9 @@ macro_rules! num_conv {
         as_item! {
             approx_dmin_to_dmax! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_blind! { ($($attrs)*), $src, $dst, ::Wrapping }
+            approx_saturating! { ($($attrs)*), $src, $dst }
+
+            conv_bounds! { ($($attrs)*), $src, $dst, min_of!($dst) as $src, max_of!($dst) as $src }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -240,6 +464,9 @@ macro_rules! num_conv {
         as_item! {
             approx_z_up! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_blind! { ($($attrs)*), $src, $dst, ::Wrapping }
+            approx_saturating! { ($($attrs)*), $src, $dst }
+
+            conv_bounds! { ($($attrs)*), $src, $dst, 0, <$src>::MAX }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -261,6 +488,34 @@ macro_rules! num_conv {
         as_item! {
             approx_blind! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_blind! { ($($attrs)*), $src, $dst, ::Wrapping }
+            approx_saturating! { ($($attrs)*), $src, $dst }
+
+            conv_bounds! { ($($attrs)*), $src, $dst, <$src>::MIN, <$src>::MAX }
+
+            $($attrs)*
+            impl ::ValueFrom<$src> for $dst {
+                type Err = ::errors::NoError;
+                #[inline]
+                fn value_from(src: $src) -> Result<$dst, Self::Err> {
+                    Ok(src as $dst)
+                }
+            }
+        }
+        num_conv! { @ $src=> $($tail)* }
+    };
+
+    // Widening *into* a floating-point type; every rounding scheme is exact.
+    (@ $src:ty=> ($($attrs:tt)*) wf $dst:ident, $($tail:tt)*) => {
+        as_item! {
+            approx_blind! { ($($attrs)*), $src, $dst, ::RoundToNearest }
+            approx_blind! { ($($attrs)*), $src, $dst, ::RoundHalfEven }
+            approx_blind! { ($($attrs)*), $src, $dst, ::RoundToZero }
+            approx_blind! { ($($attrs)*), $src, $dst, ::RoundToNegInf }
+            approx_blind! { ($($attrs)*), $src, $dst, ::RoundToPosInf }
+            approx_blind! { ($($attrs)*), $src, $dst, ::DefaultApprox }
+            approx_blind! { ($($attrs)*), $src, $dst, ::Wrapping }
+
+            conv_bounds! { ($($attrs)*), $src, $dst, <$src>::MIN, <$src>::MAX }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -278,6 +533,9 @@ macro_rules! num_conv {
     (@ $src:ty=> ($($attrs:tt)*) nf [+- $bound:expr] $dst:ident, $($tail:tt)*) => {
         as_item! {
             approx_blind! { ($($attrs)*), $src, $dst, ::DefaultApprox }
+            approx_int_to_float_rounded! { ($($attrs)*), $src, $dst }
+
+            conv_bounds! { ($($attrs)*), $src, $dst, -$bound, $bound }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -300,6 +558,32 @@ macro_rules! num_conv {
     (@ $src:ty=> ($($attrs:tt)*) nf [, $max:expr] $dst:ident, $($tail:tt)*) => {
         as_item! {
             approx_blind! { ($($attrs)*), $src, $dst, ::DefaultApprox }
+            approx_int_to_float_rounded! { ($($attrs)*), $src, $dst }
+
+            conv_bounds! { ($($attrs)*), $src, $dst, <$src>::MIN, $max }
+
+            $($attrs)*
+            impl ::ValueFrom<$src> for $dst {
+                type Err = ::errors::PosOverflow<$src>;
+                #[inline]
+                fn value_from(src: $src) -> Result<$dst, Self::Err> {
+                    if !(src <= $max) {
+                        return Err(::errors::PosOverflow(src));
+                    }
+                    Ok(src as $dst)
+                }
+            }
+        }
+        num_conv! { @ $src=> $($tail)* }
+    };
+
+    // As above, except that the largest source values round past the destination type's maximum finite value.
+    (@ $src:ty=> ($($attrs:tt)*) nfo [, $max:expr] $dst:ident, $($tail:tt)*) => {
+        as_item! {
+            approx_to_dfinite! { ($($attrs)*), $src, $dst, ::DefaultApprox }
+            approx_int_to_float_rounded! { ($($attrs)*), $src, $dst, +inf }
+
+            conv_bounds! { ($($attrs)*), $src, $dst, <$src>::MIN, $max }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -322,12 +606,36 @@ macro_rules! num_conv {
             approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, ::RoundToNearest,
                 approx: |s| s.round() }
+            approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, ::RoundHalfEven,
+                approx: |s| s.round_ties_even() }
             approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, ::RoundToNegInf,
                 approx: |s| s.floor() }
             approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, ::RoundToPosInf,
                 approx: |s| s.ceil() }
             approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, ::RoundToZero,
                 approx: |s| s.trunc() }
+            approx_saturating_no_nan! { ($($attrs)*), $src, $dst }
+            approx_wrapping_no_nan! { ($($attrs)*), $src, $dst }
+        }
+        num_conv! { @ $src=> $($tail)* }
+    };
+
+    // As `fan`, for destinations whose maximum rounds up when converted to the source type; see `approx_dmin_to_pow2_no_nan`.
+    (@ $src:ty=> ($($attrs:tt)*) fanx $dst:ident, $($tail:tt)*) => {
+        as_item! {
+            approx_dmin_to_pow2_no_nan! { ($($attrs)*), $src, $dst, ::DefaultApprox }
+            approx_dmin_to_pow2_no_nan! { ($($attrs)*), $src, $dst, ::RoundToNearest,
+                approx: |s| s.round() }
+            approx_dmin_to_pow2_no_nan! { ($($attrs)*), $src, $dst, ::RoundHalfEven,
+                approx: |s| s.round_ties_even() }
+            approx_dmin_to_pow2_no_nan! { ($($attrs)*), $src, $dst, ::RoundToNegInf,
+                approx: |s| s.floor() }
+            approx_dmin_to_pow2_no_nan! { ($($attrs)*), $src, $dst, ::RoundToPosInf,
+                approx: |s| s.ceil() }
+            approx_dmin_to_pow2_no_nan! { ($($attrs)*), $src, $dst, ::RoundToZero,
+                approx: |s| s.trunc() }
+            approx_saturating_no_nan! { ($($attrs)*), $src, $dst }
+            approx_wrapping_no_nan! { ($($attrs)*), $src, $dst }
         }
         num_conv! { @ $src=> $($tail)* }
     };
@@ -338,32 +646,46 @@ macro_rules! num_conv {
 }
 
 mod lang_ints {
-    num_conv! { i8=>  w i16, w i32, w i64, w+u8, w+u16, w+u32, w+u64, w isize, w+usize }
-    num_conv! { i16=> n i8, w i32, w i64, n+u8, w+u16, w+u32, w+u64, w isize, w+usize }
-    num_conv! { i32=> n i8, n i16, w i64, n+u8, n+u16, w+u32, w+u64 }
-    num_conv! { i64=> n i8, n i16, n i32, n+u8, n+u16, n+u32, w+u64 }
+    num_conv! { i8=>  w i16, w i32, w i64, w i128, w+u8, w+u16, w+u32, w+u64, w+u128, w isize, w+usize }
+    num_conv! { i16=> n i8, w i32, w i64, w i128, n+u8, w+u16, w+u32, w+u64, w+u128, w isize, w+usize }
+    num_conv! { i32=> n i8, n i16, w i64, w i128, n+u8, n+u16, w+u32, w+u64, w+u128 }
+    num_conv! { i64=> n i8, n i16, n i32, w i128, n+u8, n+u16, n+u32, w+u64, w+u128 }
+    num_conv! { i128=> n i8, n i16, n i32, n i64, n+u8, n+u16, n+u32, n+u64, w+u128 }
     num_conv! { i32=> #[32] e isize, #[64] w isize, w+usize }
     num_conv! { i64=> #[32] n isize, #[64] e isize, n+usize }
+    num_conv! { i128=> n isize, n+usize }
 
-    num_conv! { u8=> n-i8, w i16, w i32, w i64, w u16, w u32, w u64, w isize, w usize }
-    num_conv! { u16=> n-i8, n-i16, w i32, w i64, n-u8, w u32, w u64, w isize, w usize }
-    num_conv! { u32=> n-i8, n-i16, n-i32, w i64, n-u8, n-u16, w u64 }
-    num_conv! { u64=> n-i8, n-i16, n-i32, n-i64, n-u8, n-u16, n-u32 }
+    num_conv! { u8=> n-i8, w i16, w i32, w i64, w i128, w u16, w u32, w u64, w u128, w isize, w usize }
+    num_conv! { u16=> n-i8, n-i16, w i32, w i64, w i128, n-u8, w u32, w u64, w u128, w isize, w usize }
+    num_conv! { u32=> n-i8, n-i16, n-i32, w i64, w i128, n-u8, n-u16, w u64, w u128 }
+    num_conv! { u64=> n-i8, n-i16, n-i32, n-i64, w i128, n-u8, n-u16, n-u32, w u128 }
+    num_conv! { u128=> n-i8, n-i16, n-i32, n-i64, n-i128, n-u8, n-u16, n-u32, n-u64 }
     num_conv! { u32=> #[32] n-isize, #[64] w isize, #[32] e usize, #[64] w usize }
     num_conv! { u64=> n-isize, #[32] n-usize, #[64] e usize }
+    num_conv! { u128=> n-isize, n-usize }
 
-    num_conv! { isize=> n i8, n i16, #[32] e i32, #[32] w i64, #[64] n i32, #[64] e i64 }
-    num_conv! { isize=> n+u8, n+u16, #[32] w+u32, #[32] w+u64, #[64] n+u32, #[64] w+u64 }
+    num_conv! { isize=> n i8, n i16, #[32] e i32, #[32] w i64, #[64] n i32, #[64] e i64, w i128 }
+    num_conv! { isize=> n+u8, n+u16, #[32] w+u32, #[32] w+u64, #[64] n+u32, #[64] w+u64, w+u128 }
     num_conv! { isize=> w+usize }
 
-    num_conv! { usize=> n-i8, n-i16, #[32] n-i32, #[32] w i64, #[64] n-i32, #[64] n-i64 }
-    num_conv! { usize=> n-u8, n-u16, #[32] e u32, #[32] w u64, #[64] n-u32, #[64] e u64 }
+    num_conv! { usize=> n-i8, n-i16, #[32] n-i32, #[32] w i64, #[64] n-i32, #[64] n-i64, w i128 }
+    num_conv! { usize=> n-u8, n-u16, #[32] e u32, #[32] w u64, #[64] n-u32, #[64] e u64, w u128 }
     num_conv! { usize=> n-isize }
+
+    item_for_each! {
+        (i8), (i16), (i32), (i64), (i128), (isize),
+        (u8), (u16), (u32), (u64), (u128), (usize) => {
+            ($ity:ident) => {
+                conv_bounds! { (), $ity, $ity, min_of!($ity), max_of!($ity) }
+            };
+        }
+    }
 }
 
 mod lang_floats {
     use {ApproxFrom, ApproxScheme};
     use ValueFrom;
+    use {RoundToNearest, RoundToNegInf, RoundToPosInf, RoundToZero};
     use errors::{NoError, RangeError};
 
     // f32 -> f64: strictly widening
@@ -384,6 +706,10 @@ mod lang_floats {
         }
     }
 
+    conv_bounds! { (), f32, f32, ::std::f32::NEG_INFINITY, ::std::f32::INFINITY }
+    conv_bounds! { (), f32, f64, ::std::f32::NEG_INFINITY, ::std::f32::INFINITY }
+    conv_bounds! { (), f64, f64, ::std::f64::NEG_INFINITY, ::std::f64::INFINITY }
+
     // f64 -> f32: narrowing, approximate
     impl ApproxFrom<f64> for f32 {
         type Err = RangeError<f64>;
@@ -401,26 +727,714 @@ mod lang_floats {
             Ok(src as f32)
         }
     }
+
+    /// Returns the next `f32` toward positive infinity.  `x` must be finite.
+    fn f32_next_up(x: f32) -> f32 {
+        if x == 0.0 {
+            f32::from_bits(1)
+        } else if x > 0.0 {
+            f32::from_bits(x.to_bits() + 1)
+        } else {
+            f32::from_bits(x.to_bits() - 1)
+        }
+    }
+
+    /// Returns the next `f32` toward negative infinity.  `x` must be finite.
+    fn f32_next_down(x: f32) -> f32 {
+        -f32_next_up(-x)
+    }
+
+    /**
+    Returns the pair of adjacent `f32` values which bracket `src`, which must be finite and within the range of `f32`.
+
+    If `src` is exactly representable, both values are equal to it.  This only relies on `as` rounding to the *nearest* `f32`; which way that rounding went is recovered by comparing the result against `src`, so the current floating point rounding mode does not matter.
+    */
+    fn f32_bracket(src: f64) -> (f32, f32) {
+        let near = src as f32;
+        if near as f64 == src {
+            (near, near)
+        } else if (near as f64) < src {
+            (near, f32_next_up(near))
+        } else {
+            (f32_next_down(near), near)
+        }
+    }
+
+    macro_rules! approx_f64_to_f32 {
+        ($scheme:ty, |$src:ident, $lo:ident, $hi:ident| $pick:expr) => {
+            impl ApproxFrom<f64, $scheme> for f32 {
+                type Err = RangeError<f64>;
+                #[inline]
+                fn approx_from(src: f64) -> Result<f32, Self::Err> {
+                    if !src.is_finite() {
+                        return Ok(src as f32);
+                    }
+                    if !(::std::f32::MIN as f64 <= src) {
+                        return Err(RangeError::NegOverflow(src));
+                    }
+                    if !(src <= ::std::f32::MAX as f64) {
+                        return Err(RangeError::PosOverflow(src));
+                    }
+                    let ($lo, $hi) = f32_bracket(src);
+                    let $src = src;
+                    Ok($pick)
+                }
+            }
+        };
+    }
+
+    approx_f64_to_f32! { RoundToNegInf, |_s, lo, _hi| lo }
+    approx_f64_to_f32! { RoundToPosInf, |_s, _lo, hi| hi }
+    approx_f64_to_f32! { RoundToZero, |s, lo, hi| if s < 0.0 { hi } else { lo } }
+    approx_f64_to_f32! { RoundToNearest, |s, lo, hi| {
+        // The midpoint of two adjacent `f32`s is always exactly representable as an `f64`.
+        let mid = (lo as f64 + hi as f64) / 2.0;
+        if s < mid {
+            lo
+        } else if mid < s {
+            hi
+        } else if s < 0.0 {
+            lo
+        } else {
+            hi
+        }
+    } }
+}
+
+/// How an integer magnitude is rounded to fit a floating point significand.
+#[derive(Copy, Clone)]
+enum IntRound {
+    /// Toward zero.
+    Trunc,
+    /// Away from zero.
+    Away,
+    /// To nearest, with ties away from zero.
+    HalfAway,
+    /// To nearest, with ties to even.
+    HalfEven,
+}
+
+/// Splits an integer into its sign and magnitude.
+trait SignMag {
+    fn sign_mag(self) -> (bool, u128);
+}
+
+item_for_each! {
+    (i8), (i16), (i32), (i64), (i128), (isize) => {
+        ($ity:ident) => {
+            impl SignMag for $ity {
+                #[inline]
+                fn sign_mag(self) -> (bool, u128) {
+                    (self < 0, (self as i128).unsigned_abs())
+                }
+            }
+        };
+    }
+}
+
+item_for_each! {
+    (u8), (u16), (u32), (u64), (u128), (usize) => {
+        ($ity:ident) => {
+            impl SignMag for $ity {
+                #[inline]
+                fn sign_mag(self) -> (bool, u128) {
+                    (false, self as u128)
+                }
+            }
+        };
+    }
+}
+
+/**
+Rounds an integer magnitude to `digits` significant bits.
+
+The result is returned as `(mant, exp)` such that the rounded value is exactly `mant * 2^exp`, and `mant` is at most `2^digits`.  Both parts are exactly representable in a float with `digits` bits of precision, so the final value can be assembled without any further rounding.
+*/
+#[inline]
+fn round_int_mag(mag: u128, digits: u32, round: IntRound) -> (u128, u32) {
+    let bits = 128 - mag.leading_zeros();
+    if bits <= digits {
+        return (mag, 0);
+    }
+    let exp = bits - digits;
+    let mant = mag >> exp;
+    let rem = mag & ((1 << exp) - 1);
+    let half = 1 << (exp - 1);
+    let up = match round {
+        IntRound::Trunc => false,
+        IntRound::Away => rem != 0,
+        IntRound::HalfAway => rem >= half,
+        IntRound::HalfEven => rem > half || (rem == half && mant & 1 == 1),
+    };
+    (if up { mant + 1 } else { mant }, exp)
+}
+
+/// Truncates a finite float toward zero, reducing the result modulo 2^128.
+trait WrapTrunc {
+    fn wrap_trunc(self) -> u128;
 }
 
+macro_rules! wrap_trunc {
+    ($fty:ident, $bits:expr, $frac_bits:expr, $exp_mask:expr, $exp_bias:expr) => {
+        impl WrapTrunc for $fty {
+            #[inline]
+            fn wrap_trunc(self) -> u128 {
+                // Decompose into `mant * 2^exp`, where `mant` is an integer.
+                let bits = self.to_bits();
+                let frac = (bits & ((1 << $frac_bits) - 1)) as u128;
+                let exp_bits = ((bits >> $frac_bits) & $exp_mask) as i32;
+                let (mant, exp) = match exp_bits {
+                    0 => (frac, 1 - $exp_bias - $frac_bits),
+                    _ => (frac | (1 << $frac_bits), exp_bits - $exp_bias - $frac_bits),
+                };
+                let mag = if exp < 0 {
+                    mant.checked_shr(-exp as u32).unwrap_or(0)
+                } else {
+                    mant.checked_shl(exp as u32).unwrap_or(0)
+                };
+                if (bits >> ($bits - 1)) != 0 { mag.wrapping_neg() } else { mag }
+            }
+        }
+    };
+}
+
+wrap_trunc! { f32, 32, 23, 0xff, 127 }
+wrap_trunc! { f64, 64, 52, 0x7ff, 1023 }
+
 mod lang_int_to_float {
-    num_conv! { i8=>  w f32, w f64 }
-    num_conv! { i16=> w f32, w f64 }
-    num_conv! { i32=> nf [+- 16_777_216] f32, w f64 }
+    num_conv! { i8=>  wf f32, wf f64 }
+    num_conv! { i16=> wf f32, wf f64 }
+    num_conv! { i32=> nf [+- 16_777_216] f32, wf f64 }
     num_conv! { i64=> nf [+- 16_777_216] f32, nf [+- 9_007_199_254_740_992] f64 }
+    num_conv! { i128=> nf [+- 16_777_216] f32, nf [+- 9_007_199_254_740_992] f64 }
+    num_conv! { isize=> nf [+- 16_777_216] f32, #[32] wf f64, #[64] nf [+- 9_007_199_254_740_992] f64 }
 
-    num_conv! { u8=>  w f32, w f64 }
-    num_conv! { u16=> w f32, w f64 }
-    num_conv! { u32=> nf [, 16_777_216] f32, w f64 }
+    num_conv! { u8=>  wf f32, wf f64 }
+    num_conv! { u16=> wf f32, wf f64 }
+    num_conv! { u32=> nf [, 16_777_216] f32, wf f64 }
     num_conv! { u64=> nf [, 16_777_216] f32, nf [, 9_007_199_254_740_992] f64 }
+    num_conv! { u128=> nfo [, 16_777_216] f32, nf [, 9_007_199_254_740_992] f64 }
+    num_conv! { usize=> nf [, 16_777_216] f32, #[32] wf f64, #[64] nf [, 9_007_199_254_740_992] f64 }
 }
 
 mod lang_float_to_int {
-    num_conv! { f32=> fan i8, fan i16, fan i32, fan i64 }
-    num_conv! { f32=> fan u8, fan u16, fan u32, fan u64 }
-    num_conv! { f32=> fan isize, fan usize }
+    // `fanx` is used wherever the destination's maximum is not exactly representable in the source.
+    num_conv! { f32=> fan i8, fan i16, fanx i32, fanx i64, fanx i128 }
+    num_conv! { f32=> fan u8, fan u16, fanx u32, fanx u64, fanx u128 }
+    num_conv! { f32=> fanx isize, fanx usize }
+
+    num_conv! { f64=> fan i8, fan i16, fan i32, fanx i64, fanx i128 }
+    num_conv! { f64=> fan u8, fan u16, fan u32, fanx u64, fanx u128 }
+    num_conv! { f64=> #[32] fan isize, #[64] fanx isize, #[32] fan usize, #[64] fanx usize }
+}
+
+mod lang_char {
+    use {ApproxFrom, TryFrom, ValueFrom, DefaultApprox, Wrapping};
+    use errors::{NoError, PosOverflow, Unrepresentable};
+
+    // char -> int: every code point fits, unless the destination is narrower than 21 bits.
+    item_for_each! {
+        (i32), (i64), (i128), (isize), (u32), (u64), (u128), (usize) => {
+            ($dst:ident) => {
+                impl ValueFrom<char> for $dst {
+                    type Err = NoError;
+                    #[inline]
+                    fn value_from(src: char) -> Result<$dst, Self::Err> {
+                        Ok(src as $dst)
+                    }
+                }
+
+                impl ApproxFrom<char, DefaultApprox> for $dst {
+                    type Err = NoError;
+                    #[inline]
+                    fn approx_from(src: char) -> Result<$dst, Self::Err> {
+                        Ok(src as $dst)
+                    }
+                }
+            };
+        }
+    }
+
+    item_for_each! {
+        (i8), (i16), (u8), (u16) => {
+            ($dst:ident) => {
+                impl ValueFrom<char> for $dst {
+                    type Err = PosOverflow<char>;
+                    #[inline]
+                    fn value_from(src: char) -> Result<$dst, Self::Err> {
+                        if !(src as u32 <= ::std::$dst::MAX as u32) {
+                            return Err(PosOverflow(src));
+                        }
+                        Ok(src as $dst)
+                    }
+                }
+
+                impl ApproxFrom<char, DefaultApprox> for $dst {
+                    type Err = PosOverflow<char>;
+                    #[inline]
+                    fn approx_from(src: char) -> Result<$dst, Self::Err> {
+                        ValueFrom::value_from(src)
+                    }
+                }
+            };
+        }
+    }
+
+    item_for_each! {
+        (i8), (i16), (i32), (i64), (i128), (isize),
+        (u8), (u16), (u32), (u64), (u128), (usize) => {
+            ($dst:ident) => {
+                impl ApproxFrom<char, Wrapping> for $dst {
+                    type Err = NoError;
+                    #[inline]
+                    fn approx_from(src: char) -> Result<$dst, Self::Err> {
+                        Ok(src as u32 as $dst)
+                    }
+                }
+            };
+        }
+    }
+
+    // int -> char: only Unicode scalar values are valid.
+    impl ValueFrom<u8> for char {
+        type Err = NoError;
+        #[inline]
+        fn value_from(src: u8) -> Result<char, Self::Err> {
+            Ok(src as char)
+        }
+    }
+
+    impl TryFrom<u8> for char {
+        type Err = NoError;
+        #[inline]
+        fn try_from(src: u8) -> Result<char, Self::Err> {
+            Ok(src as char)
+        }
+    }
+
+    item_for_each! {
+        (i8), (i16), (i32), (i64), (i128), (isize),
+        (u16), (u32), (u64), (u128), (usize) => {
+            ($src:ident) => {
+                impl ValueFrom<$src> for char {
+                    type Err = Unrepresentable<$src>;
+                    #[inline]
+                    fn value_from(src: $src) -> Result<char, Self::Err> {
+                        if !(0 <= src as i128 && src as i128 <= ::std::u32::MAX as i128) {
+                            return Err(Unrepresentable(src));
+                        }
+                        match ::std::char::from_u32(src as u32) {
+                            Some(c) => Ok(c),
+                            None => Err(Unrepresentable(src)),
+                        }
+                    }
+                }
+
+                impl TryFrom<$src> for char {
+                    type Err = Unrepresentable<$src>;
+                    #[inline]
+                    fn try_from(src: $src) -> Result<char, Self::Err> {
+                        ValueFrom::value_from(src)
+                    }
+                }
+            };
+        }
+    }
+}
+
+mod lang_bool {
+    use {TryFrom, ValueFrom};
+    use errors::{NoError, Unrepresentable};
+
+    item_for_each! {
+        (i8), (i16), (i32), (i64), (i128), (isize),
+        (u8), (u16), (u32), (u64), (u128), (usize) => {
+            ($ity:ident) => {
+                impl ValueFrom<bool> for $ity {
+                    type Err = NoError;
+                    #[inline]
+                    fn value_from(src: bool) -> Result<$ity, Self::Err> {
+                        Ok(src as $ity)
+                    }
+                }
+
+                impl TryFrom<$ity> for bool {
+                    type Err = Unrepresentable<$ity>;
+                    #[inline]
+                    fn try_from(src: $ity) -> Result<bool, Self::Err> {
+                        match src {
+                            0 => Ok(false),
+                            1 => Ok(true),
+                            _ => Err(Unrepresentable(src)),
+                        }
+                    }
+                }
+            };
+        }
+    }
+}
+
+mod std_nonzero {
+    use std::num::{
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+        NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    };
+    use {TryFrom, ValueFrom};
+    use errors::{NoError, ZeroError};
+
+    macro_rules! nonzero_prim {
+        ($(($nz:ident: $prim:ident))*) => {
+            $(
+                impl ValueFrom<$nz> for $prim {
+                    type Err = NoError;
+                    #[inline]
+                    fn value_from(src: $nz) -> Result<$prim, Self::Err> {
+                        Ok(src.get())
+                    }
+                }
+
+                impl ValueFrom<$prim> for $nz {
+                    type Err = ZeroError<$prim>;
+                    #[inline]
+                    fn value_from(src: $prim) -> Result<$nz, Self::Err> {
+                        match $nz::new(src) {
+                            Some(v) => Ok(v),
+                            None => Err(ZeroError(src)),
+                        }
+                    }
+                }
+
+                impl TryFrom<$prim> for $nz {
+                    type Err = ZeroError<$prim>;
+                    #[inline]
+                    fn try_from(src: $prim) -> Result<$nz, Self::Err> {
+                        ValueFrom::value_from(src)
+                    }
+                }
+            )*
+        };
+    }
+
+    // Value conversions preserve non-zero-ness, so these defer to the underlying integers.  As
+    // some of those cannot fail, there is no error to report a zero with; the result is instead
+    // rebuilt without checking.
+    macro_rules! nonzero_conv {
+        (($src:ident: $sprim:ident)=> $(($dst:ident: $dprim:ident))*) => {
+            $(
+                impl ValueFrom<$src> for $dst {
+                    type Err = <$dprim as ValueFrom<$sprim>>::Err;
+                    #[inline]
+                    fn value_from(src: $src) -> Result<$dst, Self::Err> {
+                        match <$dprim as ValueFrom<$sprim>>::value_from(src.get()) {
+                            // Safe, as `v` has the same value as `src`, which is not zero.
+                            Ok(v) => Ok(unsafe { $dst::new_unchecked(v) }),
+                            Err(e) => Err(e),
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    nonzero_prim! {
+        (NonZeroI8: i8) (NonZeroI16: i16) (NonZeroI32: i32) (NonZeroI64: i64)
+        (NonZeroI128: i128) (NonZeroIsize: isize)
+        (NonZeroU8: u8) (NonZeroU16: u16) (NonZeroU32: u32) (NonZeroU64: u64)
+        (NonZeroU128: u128) (NonZeroUsize: usize)
+    }
+
+    distinct_pairs! { nonzero_conv!
+        (NonZeroI8: i8) (NonZeroI16: i16) (NonZeroI32: i32) (NonZeroI64: i64)
+        (NonZeroI128: i128) (NonZeroIsize: isize)
+        (NonZeroU8: u8) (NonZeroU16: u16) (NonZeroU32: u32) (NonZeroU64: u64)
+        (NonZeroU128: u128) (NonZeroUsize: usize)
+    }
+}
+
+mod std_wrapping {
+    use std::num::Wrapping;
+    use {ApproxFrom, ApproxScheme, ValueFrom, UnwrapOk};
+    use errors::NoError;
+
+    item_for_each! {
+        (i8), (i16), (i32), (i64), (i128), (isize),
+        (u8), (u16), (u32), (u64), (u128), (usize) => {
+            ($ity:ident) => {
+                impl ValueFrom<$ity> for Wrapping<$ity> {
+                    type Err = NoError;
+                    #[inline]
+                    fn value_from(src: $ity) -> Result<Wrapping<$ity>, Self::Err> {
+                        Ok(Wrapping(src))
+                    }
+                }
+
+                impl ValueFrom<Wrapping<$ity>> for $ity {
+                    type Err = NoError;
+                    #[inline]
+                    fn value_from(src: Wrapping<$ity>) -> Result<$ity, Self::Err> {
+                        Ok(src.0)
+                    }
+                }
+            };
+        }
+    }
+
+    // Changing the width of a wrapping integer wraps, whatever the requested scheme.
+    macro_rules! wrapping_conv {
+        (($src:ident)=> $(($dst:ident))*) => {
+            $(
+                impl ValueFrom<Wrapping<$src>> for Wrapping<$dst> {
+                    type Err = NoError;
+                    #[inline]
+                    fn value_from(src: Wrapping<$src>) -> Result<Wrapping<$dst>, Self::Err> {
+                        Ok(Wrapping(<$dst as ApproxFrom<$src, ::Wrapping>>::approx_from(src.0).unwrap_ok()))
+                    }
+                }
+
+                impl<Scheme> ApproxFrom<Wrapping<$src>, Scheme> for Wrapping<$dst>
+                where Scheme: ApproxScheme {
+                    type Err = NoError;
+                    #[inline]
+                    fn approx_from(src: Wrapping<$src>) -> Result<Wrapping<$dst>, Self::Err> {
+                        ValueFrom::value_from(src)
+                    }
+                }
+            )*
+        };
+    }
+
+    distinct_pairs! { wrapping_conv!
+        (i8) (i16) (i32) (i64) (i128) (isize)
+        (u8) (u16) (u32) (u64) (u128) (usize)
+    }
+}
+
+mod std_saturating {
+    use std::num::Saturating;
+    use {ApproxFrom, ApproxScheme, ValueFrom, UnwrapOk};
+    use errors::NoError;
+
+    item_for_each! {
+        (i8), (i16), (i32), (i64), (i128), (isize),
+        (u8), (u16), (u32), (u64), (u128), (usize) => {
+            ($ity:ident) => {
+                impl ValueFrom<$ity> for Saturating<$ity> {
+                    type Err = NoError;
+                    #[inline]
+                    fn value_from(src: $ity) -> Result<Saturating<$ity>, Self::Err> {
+                        Ok(Saturating(src))
+                    }
+                }
+
+                impl ValueFrom<Saturating<$ity>> for $ity {
+                    type Err = NoError;
+                    #[inline]
+                    fn value_from(src: Saturating<$ity>) -> Result<$ity, Self::Err> {
+                        Ok(src.0)
+                    }
+                }
+            };
+        }
+    }
+
+    // Changing the width of a saturating integer saturates, whatever the requested scheme.
+    macro_rules! saturating_conv {
+        (($src:ident)=> $(($dst:ident))*) => {
+            $(
+                impl ValueFrom<Saturating<$src>> for Saturating<$dst> {
+                    type Err = NoError;
+                    #[inline]
+                    fn value_from(src: Saturating<$src>) -> Result<Saturating<$dst>, Self::Err> {
+                        Ok(Saturating(<$dst as ApproxFrom<$src, ::Saturating>>::approx_from(src.0).unwrap_ok()))
+                    }
+                }
 
-    num_conv! { f64=> fan i8, fan i16, fan i32, fan i64 }
-    num_conv! { f64=> fan u8, fan u16, fan u32, fan u64 }
-    num_conv! { f64=> fan isize, fan usize }
+                impl<Scheme> ApproxFrom<Saturating<$src>, Scheme> for Saturating<$dst>
+                where Scheme: ApproxScheme {
+                    type Err = NoError;
+                    #[inline]
+                    fn approx_from(src: Saturating<$src>) -> Result<Saturating<$dst>, Self::Err> {
+                        ValueFrom::value_from(src)
+                    }
+                }
+            )*
+        };
+    }
+
+    distinct_pairs! { saturating_conv!
+        (i8) (i16) (i32) (i64) (i128) (isize)
+        (u8) (u16) (u32) (u64) (u128) (usize)
+    }
+}
+
+mod std_collections {
+    use std::iter::FromIterator;
+    use {ApproxFrom, ApproxIntoEach, ApproxScheme, ValueFrom, ValueIntoEach};
+    use errors::{ElementError, ElementErrors};
+
+    fn first_failure<I, F, Dst, E, C>(iter: I, mut conv: F) -> Result<C, ElementError<E>>
+    where
+        I: Iterator,
+        F: FnMut(I::Item) -> Result<Dst, E>,
+        C: FromIterator<Dst>,
+    {
+        iter.enumerate()
+            .map(|(index, src)| conv(src)
+                .map_err(|error| ElementError { index, error }))
+            .collect()
+    }
+
+    fn all_failures<I, F, Dst, E>(iter: I, mut conv: F) -> Result<Vec<Dst>, ElementErrors<E>>
+    where
+        I: Iterator,
+        F: FnMut(I::Item) -> Result<Dst, E>,
+    {
+        let mut dsts = Vec::with_capacity(iter.size_hint().0);
+        let mut errs = vec![];
+        for (index, src) in iter.enumerate() {
+            match conv(src) {
+                Ok(dst) => if errs.is_empty() { dsts.push(dst) },
+                Err(error) => errs.push(ElementError { index, error }),
+            }
+        }
+        if errs.is_empty() {
+            Ok(dsts)
+        } else {
+            Err(ElementErrors(errs))
+        }
+    }
+
+    fn into_array<T, const N: usize>(v: Vec<T>) -> [T; N] {
+        match <[T; N] as ::std::convert::TryFrom<Vec<T>>>::try_from(v) {
+            Ok(arr) => arr,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /*
+    Each collection is described by the type of its elements, how to turn it into an iterator of elements, and how to turn a `Vec` of converted elements back into the destination collection.
+    */
+    macro_rules! each_impls {
+        ($(
+            ($($gen:tt)*) $src:ty => $dst:ty, $iter:expr, $wrap:expr;
+        )*) => {
+            $(
+                impl<$($gen)* Src, Dst> ValueIntoEach<$dst> for $src
+                where Dst: ValueFrom<Src> {
+                    type Err = Dst::Err;
+
+                    #[inline]
+                    fn value_into_each(self) -> Result<$dst, ElementError<Self::Err>> {
+                        first_failure::<_, _, _, _, Vec<_>>($iter(self), Dst::value_from).map($wrap)
+                    }
+
+                    #[inline]
+                    fn value_into_each_all(self) -> Result<$dst, ElementErrors<Self::Err>> {
+                        all_failures($iter(self), Dst::value_from).map($wrap)
+                    }
+                }
+
+                impl<$($gen)* Src, Dst, Scheme> ApproxIntoEach<$dst, Scheme> for $src
+                where Dst: ApproxFrom<Src, Scheme>, Scheme: ApproxScheme {
+                    type Err = Dst::Err;
+
+                    #[inline]
+                    fn approx_into_each(self) -> Result<$dst, ElementError<Self::Err>> {
+                        first_failure::<_, _, _, _, Vec<_>>($iter(self), Dst::approx_from).map($wrap)
+                    }
+
+                    #[inline]
+                    fn approx_into_each_all(self) -> Result<$dst, ElementErrors<Self::Err>> {
+                        all_failures($iter(self), Dst::approx_from).map($wrap)
+                    }
+                }
+            )*
+        };
+    }
+
+    each_impls! {
+        () Vec<Src> => Vec<Dst>, Vec::into_iter, |v| v;
+        () Box<[Src]> => Box<[Dst]>, |s: Box<[Src]>| s.into_vec().into_iter(), Vec::into_boxed_slice;
+        (const N: usize,) [Src; N] => [Dst; N], IntoIterator::into_iter, into_array;
+    }
+
+    impl<Src, Dst> ValueIntoEach<Vec<Dst>> for &[Src]
+    where Src: Clone, Dst: ValueFrom<Src> {
+        type Err = Dst::Err;
+
+        #[inline]
+        fn value_into_each(self) -> Result<Vec<Dst>, ElementError<Self::Err>> {
+            first_failure(self.iter().cloned(), Dst::value_from)
+        }
+
+        #[inline]
+        fn value_into_each_all(self) -> Result<Vec<Dst>, ElementErrors<Self::Err>> {
+            all_failures(self.iter().cloned(), Dst::value_from)
+        }
+    }
+
+    impl<Src, Dst, Scheme> ApproxIntoEach<Vec<Dst>, Scheme> for &[Src]
+    where Src: Clone, Dst: ApproxFrom<Src, Scheme>, Scheme: ApproxScheme {
+        type Err = Dst::Err;
+
+        #[inline]
+        fn approx_into_each(self) -> Result<Vec<Dst>, ElementError<Self::Err>> {
+            first_failure(self.iter().cloned(), Dst::approx_from)
+        }
+
+        #[inline]
+        fn approx_into_each_all(self) -> Result<Vec<Dst>, ElementErrors<Self::Err>> {
+            all_failures(self.iter().cloned(), Dst::approx_from)
+        }
+    }
+}
+
+mod std_tuples {
+    use {ApproxFrom, ApproxIntoFields, ApproxScheme, ValueFrom, ValueIntoFields};
+    use errors::FieldError;
+
+    macro_rules! tuple_conv {
+        ($(($($idx:tt $src:ident $dst:ident $var:ident),+))*) => {
+            $(
+                impl<$($src, $dst),+> ValueIntoFields<($($dst,)+)> for ($($src,)+)
+                where $($dst: ValueFrom<$src>),+ {
+                    type Err = FieldError<$(<$dst as ValueFrom<$src>>::Err),+>;
+
+                    #[inline]
+                    fn value_into_fields(self) -> Result<($($dst,)+), Self::Err> {
+                        Ok(($(
+                            <$dst as ValueFrom<$src>>::value_from(self.$idx).map_err(FieldError::$var)?,
+                        )+))
+                    }
+                }
+
+                impl<$($src, $dst,)+ Scheme> ApproxIntoFields<($($dst,)+), Scheme> for ($($src,)+)
+                where $($dst: ApproxFrom<$src, Scheme>,)+ Scheme: ApproxScheme {
+                    type Err = FieldError<$(<$dst as ApproxFrom<$src, Scheme>>::Err),+>;
+
+                    #[inline]
+                    fn approx_into_fields(self) -> Result<($($dst,)+), Self::Err> {
+                        Ok(($(
+                            <$dst as ApproxFrom<$src, Scheme>>::approx_from(self.$idx).map_err(FieldError::$var)?,
+                        )+))
+                    }
+                }
+            )*
+        };
+    }
+
+    tuple_conv! {
+        (0 S0 D0 Field0)
+        (0 S0 D0 Field0, 1 S1 D1 Field1)
+        (0 S0 D0 Field0, 1 S1 D1 Field1, 2 S2 D2 Field2)
+        (0 S0 D0 Field0, 1 S1 D1 Field1, 2 S2 D2 Field2, 3 S3 D3 Field3)
+        (0 S0 D0 Field0, 1 S1 D1 Field1, 2 S2 D2 Field2, 3 S3 D3 Field3, 4 S4 D4 Field4)
+        (0 S0 D0 Field0, 1 S1 D1 Field1, 2 S2 D2 Field2, 3 S3 D3 Field3, 4 S4 D4 Field4, 5 S5 D5 Field5)
+        (0 S0 D0 Field0, 1 S1 D1 Field1, 2 S2 D2 Field2, 3 S3 D3 Field3, 4 S4 D4 Field4, 5 S5 D5 Field5, 6 S6 D6 Field6)
+        (0 S0 D0 Field0, 1 S1 D1 Field1, 2 S2 D2 Field2, 3 S3 D3 Field3, 4 S4 D4 Field4, 5 S5 D5 Field5, 6 S6 D6 Field6, 7 S7 D7 Field7)
+        (0 S0 D0 Field0, 1 S1 D1 Field1, 2 S2 D2 Field2, 3 S3 D3 Field3, 4 S4 D4 Field4, 5 S5 D5 Field5, 6 S6 D6 Field6, 7 S7 D7 Field7, 8 S8 D8 Field8)
+        (0 S0 D0 Field0, 1 S1 D1 Field1, 2 S2 D2 Field2, 3 S3 D3 Field3, 4 S4 D4 Field4, 5 S5 D5 Field5, 6 S6 D6 Field6, 7 S7 D7 Field7, 8 S8 D8 Field8, 9 S9 D9 Field9)
+        (0 S0 D0 Field0, 1 S1 D1 Field1, 2 S2 D2 Field2, 3 S3 D3 Field3, 4 S4 D4 Field4, 5 S5 D5 Field5, 6 S6 D6 Field6, 7 S7 D7 Field7, 8 S8 D8 Field8, 9 S9 D9 Field9, 10 S10 D10 Field10)
+        (0 S0 D0 Field0, 1 S1 D1 Field1, 2 S2 D2 Field2, 3 S3 D3 Field3, 4 S4 D4 Field4, 5 S5 D5 Field5, 6 S6 D6 Field6, 7 S7 D7 Field7, 8 S8 D8 Field8, 9 S9 D9 Field9, 10 S10 D10 Field10, 11 S11 D11 Field11)
+    }
 }
@@ -0,0 +1,296 @@
+/*!
+This module defines an extension trait for converting every item produced by an iterator, along with the iterator adaptors it returns.
+
+See the [`ConvIterUtil`](./trait.ConvIterUtil.html) trait.
+*/
+
+use std::marker::PhantomData;
+use {ApproxInto, ApproxScheme, DefaultApprox, ValueInto};
+use errors::{ElementError, RangeErrorKind, UnwrapOrSaturate};
+use misc::Saturated;
+
+/**
+This extension trait exists to simplify converting every item produced by an iterator.
+
+The adaptor methods are prefixed with `map_` where they would otherwise clash with the methods of [`ConvUtil`](../trait.ConvUtil.html), which is implemented for *all* types, including iterators.
+
+```
+# use conv::prelude::*;
+let readings = vec![12i64, -1, 300, 7];
+
+let raw: Vec<_> = readings.iter().cloned().map_value_as::<u8>().collect();
+assert!(raw[1].is_err());
+
+let saturated: Vec<u8> = readings.iter().cloned().saturating_as().collect();
+assert_eq!(saturated, vec![12, 0, 255, 7]);
+
+let valid: Vec<u8> = readings.iter().cloned().skip_unconvertible().collect();
+assert_eq!(valid, vec![12, 7]);
+
+let failed: Vec<usize> = readings.iter().cloned().value_failures::<u8>().map(|e| e.index).collect();
+assert_eq!(failed, vec![1, 2]);
+```
+
+> **Note**: There appears to be a bug in `rustdoc`'s output.  This trait is implemented *for all* iterators, though the methods are only available where the appropriate conversions are defined.
+*/
+pub trait ConvIterUtil: Iterator + Sized {
+    /// Approximate each item to a given type with the default scheme.
+    fn map_approx_as<Dst>(self) -> ApproxAs<Self, Dst, DefaultApprox>
+    where Self::Item: ApproxInto<Dst> {
+        ApproxAs { iter: self, _marker: PhantomData }
+    }
+
+    /// Approximate each item to a given type with a specific scheme.
+    fn map_approx_as_by<Dst, Scheme>(self) -> ApproxAs<Self, Dst, Scheme>
+    where
+        Self::Item: ApproxInto<Dst, Scheme>,
+        Scheme: ApproxScheme,
+    {
+        ApproxAs { iter: self, _marker: PhantomData }
+    }
+
+    /// Attempt a value conversion of each item to a given type.
+    fn map_value_as<Dst>(self) -> ValueAs<Self, Dst>
+    where Self::Item: ValueInto<Dst> {
+        ValueAs { iter: self, _marker: PhantomData }
+    }
+
+    /// Convert each item to a given type, saturating to the type's minimum or maximum value on overflow.
+    fn saturating_as<Dst>(self) -> SaturatingAs<Self, Dst>
+    where
+        Self::Item: ValueInto<Dst>,
+        <Self::Item as ValueInto<Dst>>::Err: Into<RangeErrorKind>,
+        Dst: Saturated,
+    {
+        SaturatingAs { iter: self, _marker: PhantomData }
+    }
+
+    /// Convert each item to a given type, silently dropping any item that cannot be converted.
+    fn skip_unconvertible<Dst>(self) -> SkipUnconvertible<Self, Dst>
+    where Self::Item: ValueInto<Dst> {
+        SkipUnconvertible { iter: self, _marker: PhantomData }
+    }
+
+    /**
+    Attempt a value conversion of each item to a given type, yielding *only* the failures.
+
+    Each failure is reported along with the position of the item in the original iterator.
+    */
+    fn value_failures<Dst>(self) -> ValueFailures<Self, Dst>
+    where Self::Item: ValueInto<Dst> {
+        ValueFailures { iter: self, index: 0, _marker: PhantomData }
+    }
+}
+
+impl<I> ConvIterUtil for I where I: Iterator {}
+
+/**
+An iterator that approximates each item of an underlying iterator.
+
+See [`ConvIterUtil::map_approx_as`](./trait.ConvIterUtil.html#method.map_approx_as).
+*/
+pub struct ApproxAs<I, Dst, Scheme> {
+    iter: I,
+    _marker: PhantomData<fn() -> (Dst, Scheme)>,
+}
+
+impl<I, Dst, Scheme> Iterator for ApproxAs<I, Dst, Scheme>
+where
+    I: Iterator,
+    I::Item: ApproxInto<Dst, Scheme>,
+    Scheme: ApproxScheme,
+{
+    type Item = Result<Dst, <I::Item as ApproxInto<Dst, Scheme>>::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(ApproxInto::approx_into)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, Dst, Scheme> DoubleEndedIterator for ApproxAs<I, Dst, Scheme>
+where
+    I: DoubleEndedIterator,
+    I::Item: ApproxInto<Dst, Scheme>,
+    Scheme: ApproxScheme,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(ApproxInto::approx_into)
+    }
+}
+
+impl<I, Dst, Scheme> ExactSizeIterator for ApproxAs<I, Dst, Scheme>
+where
+    I: ExactSizeIterator,
+    I::Item: ApproxInto<Dst, Scheme>,
+    Scheme: ApproxScheme,
+{}
+
+/**
+An iterator that attempts a value conversion of each item of an underlying iterator.
+
+See [`ConvIterUtil::map_value_as`](./trait.ConvIterUtil.html#method.map_value_as).
+*/
+pub struct ValueAs<I, Dst> {
+    iter: I,
+    _marker: PhantomData<fn() -> Dst>,
+}
+
+impl<I, Dst> Iterator for ValueAs<I, Dst>
+where I: Iterator, I::Item: ValueInto<Dst> {
+    type Item = Result<Dst, <I::Item as ValueInto<Dst>>::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(ValueInto::value_into)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, Dst> DoubleEndedIterator for ValueAs<I, Dst>
+where I: DoubleEndedIterator, I::Item: ValueInto<Dst> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(ValueInto::value_into)
+    }
+}
+
+impl<I, Dst> ExactSizeIterator for ValueAs<I, Dst>
+where I: ExactSizeIterator, I::Item: ValueInto<Dst> {}
+
+/**
+An iterator that converts each item of an underlying iterator, saturating on overflow.
+
+See [`ConvIterUtil::saturating_as`](./trait.ConvIterUtil.html#method.saturating_as).
+*/
+pub struct SaturatingAs<I, Dst> {
+    iter: I,
+    _marker: PhantomData<fn() -> Dst>,
+}
+
+impl<I, Dst> Iterator for SaturatingAs<I, Dst>
+where
+    I: Iterator,
+    I::Item: ValueInto<Dst>,
+    <I::Item as ValueInto<Dst>>::Err: Into<RangeErrorKind>,
+    Dst: Saturated,
+{
+    type Item = Dst;
+
+    #[inline]
+    fn next(&mut self) -> Option<Dst> {
+        self.iter.next().map(|v| v.value_into().unwrap_or_saturate())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, Dst> DoubleEndedIterator for SaturatingAs<I, Dst>
+where
+    I: DoubleEndedIterator,
+    I::Item: ValueInto<Dst>,
+    <I::Item as ValueInto<Dst>>::Err: Into<RangeErrorKind>,
+    Dst: Saturated,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Dst> {
+        self.iter.next_back().map(|v| v.value_into().unwrap_or_saturate())
+    }
+}
+
+impl<I, Dst> ExactSizeIterator for SaturatingAs<I, Dst>
+where
+    I: ExactSizeIterator,
+    I::Item: ValueInto<Dst>,
+    <I::Item as ValueInto<Dst>>::Err: Into<RangeErrorKind>,
+    Dst: Saturated,
+{}
+
+/**
+An iterator that converts each item of an underlying iterator, skipping items that cannot be converted.
+
+See [`ConvIterUtil::skip_unconvertible`](./trait.ConvIterUtil.html#method.skip_unconvertible).
+*/
+pub struct SkipUnconvertible<I, Dst> {
+    iter: I,
+    _marker: PhantomData<fn() -> Dst>,
+}
+
+impl<I, Dst> Iterator for SkipUnconvertible<I, Dst>
+where I: Iterator, I::Item: ValueInto<Dst> {
+    type Item = Dst;
+
+    #[inline]
+    fn next(&mut self) -> Option<Dst> {
+        for v in self.iter.by_ref() {
+            if let Ok(v) = v.value_into() {
+                return Some(v);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, Dst> DoubleEndedIterator for SkipUnconvertible<I, Dst>
+where I: DoubleEndedIterator, I::Item: ValueInto<Dst> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Dst> {
+        while let Some(v) = self.iter.next_back() {
+            if let Ok(v) = v.value_into() {
+                return Some(v);
+            }
+        }
+        None
+    }
+}
+
+/**
+An iterator that attempts a value conversion of each item of an underlying iterator, yielding the position and error of each failure.
+
+See [`ConvIterUtil::value_failures`](./trait.ConvIterUtil.html#method.value_failures).
+*/
+pub struct ValueFailures<I, Dst> {
+    iter: I,
+    index: usize,
+    _marker: PhantomData<fn() -> Dst>,
+}
+
+impl<I, Dst> Iterator for ValueFailures<I, Dst>
+where I: Iterator, I::Item: ValueInto<Dst> {
+    type Item = ElementError<<I::Item as ValueInto<Dst>>::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for v in self.iter.by_ref() {
+            let index = self.index;
+            self.index += 1;
+            if let Err(error) = v.value_into() {
+                return Some(ElementError { index, error });
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
@@ -47,11 +47,20 @@ These extension methods are provided to help with some common cases:
 - [`ConvUtil::value_as<Dst>`](./trait.ConvUtil.html#method.value_as) - converts to `Dst` using `ValueInto::value_into`.
 - [`ConvAsUtil::approx`](./trait.ConvAsUtil.html#method.approx) - approximates to an inferred destination type with the `DefaultApprox` scheme.
 - [`ConvAsUtil::approx_by<S>`](./trait.ConvAsUtil.html#method.approx_by) - approximates to an inferred destination type with the scheme `S`.
+- [`ConvAsUtil::approx_each`](./trait.ConvAsUtil.html#method.approx_each) (and `approx_each_by<S>`, `approx_each_all`, `approx_each_all_by<S>`) - approximates every element of a collection to an inferred destination collection.
+- [`ConvAsUtil::approx_fields`](./trait.ConvAsUtil.html#method.approx_fields) (and `approx_fields_by<S>`) - approximates every field of a tuple to an inferred destination tuple.
+- [`ValueIntoFields::value_into_fields`](./trait.ValueIntoFields.html#tymethod.value_into_fields) - converts every field of a tuple, reporting which field failed.
+- [`ConvOptionUtil::opt_value_as<Dst>`](./trait.ConvOptionUtil.html#tymethod.opt_value_as) (and `opt_approx_as<Dst>`, `opt_approx_as_by<Dst, S>`, `opt_try_as<Dst>`) - converts the value inside an `Option`, passing `None` through.
+- [`ValueIntoEach::value_into_each`](./trait.ValueIntoEach.html#tymethod.value_into_each) (and `value_into_each_all`, plus the [`ApproxIntoEach`](./trait.ApproxIntoEach.html) equivalents) - converts every element of a `Vec`, array, boxed slice or slice, reporting the index of each failure.
+- [`ConvIterUtil`](./iter/trait.ConvIterUtil.html) - converts every item of an iterator, with adaptors that yield `Result`s (`map_value_as`, `map_approx_as`, `map_approx_as_by`), saturate (`saturating_as`), skip failures (`skip_unconvertible`), or yield only the failures and their positions (`value_failures`).
+- [`bulk::value_slice`](./bulk/fn.value_slice.html) (and `approx_slice`, `approx_slice_by<S>`) - converts a slice of floats, or of integers into floats, into another slice, checking whole chunks at once.
+- [`ConvCheck::can_value_from`](./trait.ConvCheck.html#method.can_value_from) (and `can_approx_from`, `can_approx_from_by<S>`, `can_try_from`) - checks whether a borrowed value would convert, reporting the `GeneralErrorKind` it would fail with.
 - [`Saturate::saturate`](./errors/trait.Saturate.html#tymethod.saturate) - saturates on overflow.
 - [`UnwrapOk::unwrap_ok`](./errors/trait.UnwrapOk.html#tymethod.unwrap_ok) - unwraps results from conversions that cannot fail.
 - [`UnwrapOrInf::unwrap_or_inf`](./errors/trait.UnwrapOrInf.html#tymethod.unwrap_or_inf) - saturates to ±∞ on failure.
 - [`UnwrapOrInvalid::unwrap_or_invalid`](./errors/trait.UnwrapOrInvalid.html#tymethod.unwrap_or_invalid) - substitutes the target type's "invalid" sentinel value on failure.
 - [`UnwrapOrSaturate::unwrap_or_saturate`](./errors/trait.UnwrapOrSaturate.html#tymethod.unwrap_or_saturate) - saturates to the maximum or minimum value of the target type on failure.
+- [`WithDetail::with_detail`](./errors/trait.WithDetail.html#tymethod.with_detail) - replaces the error with a `DetailedError` describing the value, types and bound involved, for logging.
 
 A macro is provided to assist in implementing conversions:
 
@@ -66,7 +75,15 @@ The crate provides several blanket implementations:
 - `*From<A> for A` (all types can be converted from and into themselves).
 - `*Into<Dst> for Src where Dst: *From<Src>` (`*From` implementations imply a matching `*Into` implementation).
 
-Conversions for the builtin numeric (integer and floating point) types are provided.  In general, `ValueFrom` conversions exist for all pairs except for float → integer (since such a conversion is generally unlikely to *exactly* succeed) and `f64 → f32` (for the same reason).  `ApproxFrom` conversions with the `DefaultApprox` scheme exist between all pairs.  `ApproxFrom` with the `Wrapping` scheme exist between integers.
+Conversions for the builtin numeric (integer and floating point) types are provided.  In general, `ValueFrom` conversions exist for all pairs except for float → integer (since such a conversion is generally unlikely to *exactly* succeed) and `f64 → f32` (for the same reason).  `ApproxFrom` conversions with the `DefaultApprox` scheme exist between all pairs.  `ApproxFrom` with the `Wrapping` and `Saturating` schemes exist between integers, and from floats to integers.  Float → integer wrapping truncates the value, then reduces it modulo 2<sup>N</sup>; only NaN and the infinities cause it to fail.  `f64 → f32` can also be approximated with any of the `RoundTo*` schemes, which choose the adjacent `f32` in the given direction.  Likewise, integer → float conversions support the `RoundTo*` and `RoundHalfEven` schemes; these are computed exactly on the integer, and produce the correctly rounded neighbour.
+
+The `std::num::NonZero*` types convert between each other with `ValueFrom`, using the same error types as the underlying integers (the error payload is the underlying integer).  They can always be converted into their underlying integer type, and the reverse conversion fails with `ZeroError`.
+
+`std::num::Wrapping<T>` and `std::num::Saturating<T>` convert to other widths of the same wrapper with `ValueFrom` (and `ApproxFrom` with any scheme), which never fail: wrapping integers wrap, and saturating integers saturate.  Both can also be converted to and from their inner type.
+
+`char` and `bool` are also supported.  `ValueFrom<char>` exists for all integers, failing with `PosOverflow` for types narrower than 21 bits; integers can be converted to `char` with `ValueFrom` or `TryFrom`, failing with `Unrepresentable` for surrogates and values above `0x10FFFF`.  `ValueFrom<bool>` exists for all integers, and `TryFrom<integer> for bool` accepts only `0` and `1`.
+
+The [`Ranged`](./ranged/struct.Ranged.html) type wraps an integer whose range is fixed at compile time.  It can be converted from all integers with `ValueFrom` and `TryFrom`, failing with `RangeError` for values outside its bounds, and into all integers with `ValueFrom`.
 
 ## Errors
 
@@ -83,6 +100,8 @@ Aside from `NoError`, the various error types wrap the input value that you atte
 
 To help alleviate this, there is also `GeneralErrorKind`, which is simply `GeneralError<T>` without the payload, and all errors can be converted into it directly.
 
+Neither of these say *which* value failed to convert, or into what, when displayed.  For that, `DetailedError` records the source and target type names, the offending value, and the bound it violated; `with_detail` converts a conversion's error into one, producing messages such as "300i32 exceeds u8::MAX (255)".
+
 The reason for not just using `GeneralErrorKind` in the first place is to statically reduce the number of potential error cases you need to deal with.  It also allows the `Unwrap*` extension traits to be defined *without* the possibility for runtime failure (*e.g.* you cannot use `unwrap_or_saturate` with a `FloatError`, because what do you do if the error is `NotANumber`; saturate to max or to min?  Or panic?).
 
 # Examples
@@ -177,13 +196,20 @@ pub mod macros;
 
 pub use errors::{
     NoError, GeneralError, GeneralErrorKind, Unrepresentable,
-    NegOverflow, PosOverflow,
+    NegOverflow, PosOverflow, ZeroError,
     FloatError, RangeError, RangeErrorKind,
+    ElementError, ElementErrors, FieldError, NamedFieldError,
+    DetailedError,
     Saturate,
     UnwrapOk, UnwrapOrInf, UnwrapOrInvalid, UnwrapOrSaturate,
+    WithDetail,
 };
 
+pub use iter::ConvIterUtil;
+pub use ranged::Ranged;
+
 use std::error::Error;
+use std::marker::PhantomData;
 
 /**
 Publicly re-exports the most generally useful set of items.
@@ -197,8 +223,11 @@ pub mod prelude {
         GeneralError, GeneralErrorKind,
         Saturate,
         UnwrapOk, UnwrapOrInf, UnwrapOrInvalid, UnwrapOrSaturate,
-        ConvUtil, ConvAsUtil,
-        RoundToNearest, RoundToZero, Wrapping,
+        ConvUtil, ConvAsUtil, ConvCheck, ConvOptionUtil,
+        ApproxIntoEach, ValueIntoEach,
+        ApproxIntoFields, ValueIntoFields,
+        ConvIterUtil,
+        RoundToNearest, RoundToZero, Saturating, Wrapping,
     };
 }
 
@@ -220,8 +249,11 @@ macro_rules! item_for_each {
     };
 }
 
+pub mod bulk;
 pub mod errors;
+pub mod iter;
 pub mod misc;
+pub mod ranged;
 
 mod impls;
 
@@ -302,12 +334,30 @@ In abstract, this can be viewed as the opposite of rounding: rather than preserv
 pub enum Wrapping {}
 impl ApproxScheme for Wrapping {}
 
+/**
+This scheme is used to convert a value by "saturating" it into a narrower range.
+
+Values beyond the range of the destination type are clamped to its minimum or maximum value, instead of causing an overflow error.  The `Round` parameter selects the scheme used to approximate the value *before* it is saturated; this only matters when converting from floating point types.  For example, `Saturating<RoundToNearest>` rounds a float to the nearest integer, then clamps the result to the destination type's range.
+
+Saturating conversions between integers cannot fail.  Conversions from floating point types can still fail if the input is NaN, in which case they return `Unrepresentable`.
+*/
+pub struct Saturating<Round=DefaultApprox>(PhantomData<Round>);
+impl<Round> ApproxScheme for Saturating<Round> where Round: ApproxScheme {}
+
 /**
 This scheme is used to convert a value by rounding it to the nearest representable value, with ties rounding away from zero.
 */
 pub enum RoundToNearest {}
 impl ApproxScheme for RoundToNearest {}
 
+/**
+This scheme is used to convert a value by rounding it to the nearest representable value, with ties rounding to the nearest even value.
+
+This is the default rounding mode of IEEE 754, and is sometimes known as "banker's rounding".  Unlike `RoundToNearest`, it does not systematically bias the result away from zero when the input frequently lies exactly halfway between two representable values.
+*/
+pub enum RoundHalfEven {}
+impl ApproxScheme for RoundHalfEven {}
+
 /**
 This scheme is used to convert a value by rounding it toward negative infinity to the nearest representable value.
 */
@@ -414,6 +464,121 @@ impl<Src, Dst> ValueInto<Dst> for Src where Dst: ValueFrom<Src> {
     }
 }
 
+/**
+This trait is used to perform a value conversion on every element of a collection.
+
+It is implemented for `Vec<Src>`, `[Src; N]` and `Box<[Src]>` (producing a collection of the same shape), and for `&[Src]` (producing a `Vec`, cloning each element), wherever `Dst: ValueFrom<Src>`.
+
+Each element is converted with `ValueFrom`, and failures are reported along with the index of the offending element.  You can either stop at the first failure (`value_into_each`), or convert everything and collect every failure (`value_into_each_all`).
+
+```
+# use conv::*;
+let readings: Vec<i64> = vec![12, -40_000, 7, 70_000];
+let res: Result<Vec<i16>, _> = readings.clone().value_into_each();
+assert_eq!(res.unwrap_err().index, 1);
+
+let res: Result<Vec<i16>, _> = readings.value_into_each_all();
+assert_eq!(res.unwrap_err().indices(), vec![1, 3]);
+```
+
+> **Note**: this is a separate trait, rather than a set of `ValueFrom` implementations, because the latter would overlap with the reflexive `impl<T> ValueFrom<T> for T`.
+*/
+pub trait ValueIntoEach<Dst>: Sized {
+    /// The error type produced by a failed conversion of a single element.
+    type Err: Error;
+
+    /// Convert every element, stopping at the first failure.
+    fn value_into_each(self) -> Result<Dst, ElementError<Self::Err>>;
+
+    /// Convert every element, reporting every failure.
+    fn value_into_each_all(self) -> Result<Dst, ElementErrors<Self::Err>>;
+}
+
+/**
+This trait is used to perform an approximate conversion on every element of a collection.
+
+This is the approximating counterpart to [`ValueIntoEach`](./trait.ValueIntoEach.html), and is implemented for the same collections.  Each element is converted with `ApproxFrom<Src, Scheme>`.
+
+As with `ApproxInto`, a call to `approx_into_each` may not be uniquely resolvable if there is more than one scheme available; the [`ConvAsUtil`](./trait.ConvAsUtil.html) methods `approx_each` and `approx_each_by` can be used to select one.
+
+```
+# use conv::*;
+let samples = vec![0.5f64, 1.0e300, 2.0];
+let res: Result<Vec<f32>, _> = samples.approx_each();
+assert_eq!(res.unwrap_err().error, RangeError::PosOverflow(1.0e300));
+```
+*/
+pub trait ApproxIntoEach<Dst, Scheme=DefaultApprox>: Sized
+where Scheme: ApproxScheme {
+    /// The error type produced by a failed approximation of a single element.
+    type Err: Error;
+
+    /// Approximate every element, stopping at the first failure.
+    fn approx_into_each(self) -> Result<Dst, ElementError<Self::Err>>;
+
+    /// Approximate every element, reporting every failure.
+    fn approx_into_each_all(self) -> Result<Dst, ElementErrors<Self::Err>>;
+}
+
+/**
+This trait is used to perform a value conversion on every field of a tuple.
+
+It is implemented for tuples of up to twelve fields, wherever each field of the destination tuple can be converted from the corresponding field of the source tuple with `ValueFrom`.  Fields are converted in order; if one fails, the error identifies the field, and carries the field's own conversion error.
+
+```
+# use conv::*;
+let res: Result<(i32, i32, f32), _> = (1i64, 2i64, 0.5f32).value_into_fields();
+assert_eq!(res, Ok((1, 2, 0.5)));
+
+let res: Result<(i32, i32, f32), _> = (1i64, 1i64 << 40, 0.5f32).value_into_fields();
+assert_eq!(res, Err(FieldError::Field1(RangeError::PosOverflow(1 << 40))));
+```
+
+> **Note**: this is a separate trait, rather than a set of `ValueFrom` implementations, because the latter would overlap with the reflexive `impl<T> ValueFrom<T> for T`.
+*/
+pub trait ValueIntoFields<Dst>: Sized {
+    /// The error type produced by a failed conversion of a field.
+    type Err: Error;
+
+    /// Convert every field of the subject.
+    fn value_into_fields(self) -> Result<Dst, Self::Err>;
+}
+
+/**
+This trait is used to perform an approximate conversion on every field of a tuple.
+
+This is the approximating counterpart to [`ValueIntoFields`](./trait.ValueIntoFields.html), where every field is approximated with the same scheme.  The [`ConvAsUtil`](./trait.ConvAsUtil.html) methods `approx_fields` and `approx_fields_by` can be used to select the scheme.
+
+```
+# use conv::*;
+let res: Result<(i32, i32, f32), _> = (1.5f64, 2.5f64, 0.1f64).approx_fields_by::<RoundToNearest>();
+assert_eq!(res, Ok((2, 3, 0.1)));
+```
+*/
+pub trait ApproxIntoFields<Dst, Scheme=DefaultApprox>: Sized
+where Scheme: ApproxScheme {
+    /// The error type produced by a failed approximation of a field.
+    type Err: Error;
+
+    /// Approximate every field of the subject.
+    fn approx_into_fields(self) -> Result<Dst, Self::Err>;
+}
+
+/**
+This trait is used to query which source values a `ValueFrom` conversion accepts, without attempting the conversion.
+
+For the builtin numeric types, the accepted values always form a single, contiguous range: `ValueFrom<Src> for Dst` succeeds for every `src` with `Dst::min_src() <= src && src <= Dst::max_src()`, and fails for every other (non-NaN) value.  For example, `<u8 as ConvBounds<i32>>::max_src()` is `255`, and `<f32 as ConvBounds<i64>>::max_src()` is `16_777_216`, the largest integer beyond which `f32` can no longer represent every value exactly.
+
+This is useful for validating data up-front, or for describing the valid range of an input to a user.
+*/
+pub trait ConvBounds<Src>: ValueFrom<Src> {
+    /// Returns the smallest source value that can be converted.
+    fn min_src() -> Src;
+
+    /// Returns the largest source value that can be converted.
+    fn max_src() -> Src;
+}
+
 /**
 This extension trait exists to simplify using various conversions.
 
@@ -486,6 +651,178 @@ pub trait ConvAsUtil<Dst> {
     {
         self.approx_into()
     }
+
+    /// Approximate every element of the subject with the default scheme, stopping at the first failure.
+    fn approx_each(self) -> Result<Dst, ElementError<<Self as ApproxIntoEach<Dst>>::Err>>
+    where Self: Sized + ApproxIntoEach<Dst> {
+        self.approx_into_each()
+    }
+
+    /// Approximate every element of the subject with a specific scheme, stopping at the first failure.
+    fn approx_each_by<Scheme>(self) -> Result<Dst, ElementError<<Self as ApproxIntoEach<Dst, Scheme>>::Err>>
+    where
+        Self: Sized + ApproxIntoEach<Dst, Scheme>,
+        Scheme: ApproxScheme,
+    {
+        self.approx_into_each()
+    }
+
+    /// Approximate every element of the subject with the default scheme, reporting every failure.
+    fn approx_each_all(self) -> Result<Dst, ElementErrors<<Self as ApproxIntoEach<Dst>>::Err>>
+    where Self: Sized + ApproxIntoEach<Dst> {
+        self.approx_into_each_all()
+    }
+
+    /// Approximate every element of the subject with a specific scheme, reporting every failure.
+    fn approx_each_all_by<Scheme>(self) -> Result<Dst, ElementErrors<<Self as ApproxIntoEach<Dst, Scheme>>::Err>>
+    where
+        Self: Sized + ApproxIntoEach<Dst, Scheme>,
+        Scheme: ApproxScheme,
+    {
+        self.approx_into_each_all()
+    }
+
+    /// Approximate every field of the subject with the default scheme.
+    fn approx_fields(self) -> Result<Dst, <Self as ApproxIntoFields<Dst>>::Err>
+    where Self: Sized + ApproxIntoFields<Dst> {
+        self.approx_into_fields()
+    }
+
+    /// Approximate every field of the subject with a specific scheme.
+    fn approx_fields_by<Scheme>(self) -> Result<Dst, <Self as ApproxIntoFields<Dst, Scheme>>::Err>
+    where
+        Self: Sized + ApproxIntoFields<Dst, Scheme>,
+        Scheme: ApproxScheme,
+    {
+        self.approx_into_fields()
+    }
 }
 
 impl<T, Dst> ConvAsUtil<Dst> for T {}
+
+/**
+This extension trait exists to lift conversions through `Option`.
+
+Each method converts the contained value (if any) with the corresponding conversion trait: `None` is passed through as `Ok(None)`, and a failed conversion produces the inner conversion's error, unchanged.
+
+```
+# use conv::prelude::*;
+# use conv::RangeError;
+let column: Option<i32> = Some(300);
+assert_eq!(column.opt_value_as::<u8>(), Err(RangeError::PosOverflow(300)));
+assert_eq!(Some(200i32).opt_value_as::<u8>(), Ok(Some(200)));
+assert_eq!(None::<i32>.opt_value_as::<u8>(), Ok(None));
+```
+
+> **Note**: these cannot be provided as implementations of the conversion traits themselves (*i.e.* `ValueFrom<Option<Src>> for Option<Dst>`), as they would overlap with the reflexive implementations (*i.e.* `ValueFrom<T> for T`).
+*/
+pub trait ConvOptionUtil<Src> {
+    /// Approximate the contained value to a given type with the default scheme.
+    fn opt_approx_as<Dst>(self) -> Result<Option<Dst>, <Dst as ApproxFrom<Src>>::Err>
+    where Dst: ApproxFrom<Src>;
+
+    /// Approximate the contained value to a given type with a specific scheme.
+    fn opt_approx_as_by<Dst, Scheme>(self) -> Result<Option<Dst>, <Dst as ApproxFrom<Src, Scheme>>::Err>
+    where
+        Dst: ApproxFrom<Src, Scheme>,
+        Scheme: ApproxScheme;
+
+    /// Attempt to convert the contained value to a given type.
+    fn opt_try_as<Dst>(self) -> Result<Option<Dst>, Dst::Err>
+    where Dst: TryFrom<Src>;
+
+    /// Attempt a value conversion of the contained value to a given type.
+    fn opt_value_as<Dst>(self) -> Result<Option<Dst>, Dst::Err>
+    where Dst: ValueFrom<Src>;
+}
+
+impl<Src> ConvOptionUtil<Src> for Option<Src> {
+    #[inline]
+    fn opt_approx_as<Dst>(self) -> Result<Option<Dst>, <Dst as ApproxFrom<Src>>::Err>
+    where Dst: ApproxFrom<Src> {
+        self.map(ApproxFrom::approx_from).transpose()
+    }
+
+    #[inline]
+    fn opt_approx_as_by<Dst, Scheme>(self) -> Result<Option<Dst>, <Dst as ApproxFrom<Src, Scheme>>::Err>
+    where
+        Dst: ApproxFrom<Src, Scheme>,
+        Scheme: ApproxScheme,
+    {
+        self.map(ApproxFrom::approx_from).transpose()
+    }
+
+    #[inline]
+    fn opt_try_as<Dst>(self) -> Result<Option<Dst>, Dst::Err>
+    where Dst: TryFrom<Src> {
+        self.map(TryFrom::try_from).transpose()
+    }
+
+    #[inline]
+    fn opt_value_as<Dst>(self) -> Result<Option<Dst>, Dst::Err>
+    where Dst: ValueFrom<Src> {
+        self.map(ValueFrom::value_from).transpose()
+    }
+}
+
+/**
+This extension trait exists to check whether a conversion would succeed, without consuming the input.
+
+Each method performs the corresponding conversion on a clone of the input, discarding the result and reducing any error to a `GeneralErrorKind`.  As a result, it always agrees with the conversion itself.  For the builtin numeric types, cloning is just a copy, so this is no more expensive than the conversion.
+
+The source type is inferred from the argument, so these are typically invoked as `u8::can_value_from(&x)`.
+
+> **Note**: There appears to be a bug in `rustdoc`'s output.  This trait is implemented *for all* types, though the methods are only available for types where the appropriate conversions are defined.
+*/
+pub trait ConvCheck<Src> {
+    /// Check whether an approximation from the given value with the default scheme would succeed.
+    fn can_approx_from(src: &Src) -> Result<(), GeneralErrorKind>
+    where
+        Self: ApproxFrom<Src>,
+        Src: Clone,
+        <Self as ApproxFrom<Src>>::Err: Into<GeneralErrorKind>,
+    {
+        <Self as ApproxFrom<Src>>::approx_from(src.clone())
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    /// Check whether an approximation from the given value with a specific scheme would succeed.
+    fn can_approx_from_by<Scheme>(src: &Src) -> Result<(), GeneralErrorKind>
+    where
+        Self: ApproxFrom<Src, Scheme>,
+        Scheme: ApproxScheme,
+        Src: Clone,
+        <Self as ApproxFrom<Src, Scheme>>::Err: Into<GeneralErrorKind>,
+    {
+        <Self as ApproxFrom<Src, Scheme>>::approx_from(src.clone())
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    /// Check whether an attempted conversion from the given value would succeed.
+    fn can_try_from(src: &Src) -> Result<(), GeneralErrorKind>
+    where
+        Self: TryFrom<Src>,
+        Src: Clone,
+        <Self as TryFrom<Src>>::Err: Into<GeneralErrorKind>,
+    {
+        <Self as TryFrom<Src>>::try_from(src.clone())
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    /// Check whether a value conversion from the given value would succeed.
+    fn can_value_from(src: &Src) -> Result<(), GeneralErrorKind>
+    where
+        Self: ValueFrom<Src>,
+        Src: Clone,
+        <Self as ValueFrom<Src>>::Err: Into<GeneralErrorKind>,
+    {
+        <Self as ValueFrom<Src>>::value_from(src.clone())
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+}
+
+impl<Src, Dst> ConvCheck<Src> for Dst {}
@@ -15,6 +15,8 @@ If a conversion fails (due to there being no matching variant for the specified
 
 It is compatible with the [`custom_derive!`](https://crates.io/crates/custom_derive) macro.
 
+The companion [`conv_derive`](https://crates.io/crates/conv_derive) crate provides a procedural `#[derive(TryFrom)]`, which also accepts several source types at once, infers the source type from `#[repr(..)]`, supports generic enumerations and catch-all variants, and produces the same `Unrepresentable` errors.
+
 ## Example
 
 Using `custom_derive!`:
@@ -62,6 +64,79 @@ TryFrom! { (i32) enum Colours {
 } }
 # fn main() {}
 ```
+
+# `flag_set!`
+
+```ignore
+macro_rules! flag_set {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident: $bits:ty {
+            $($(#[$flag_attr:meta])* const $flag:ident = $value:expr;)*
+        }
+    ) => { ... };
+}
+```
+
+This macro declares a set of flags, stored as the integer type `$bits`, with an associated constant for each named flag.  The set can only contain bits belonging to named flags.
+
+The following conversions are implemented:
+
+- `TryFrom<$bits>`, which fails with `Unrepresentable(src)` if any bits not belonging to a named flag are set.
+- `ApproxFrom<$bits, Wrapping>`, which silently drops any such bits.
+- `ValueFrom<$name> for $bits`, which returns the bits of the set.
+
+The constants have the same visibility as the type.  The type also implements `Copy`, `Eq`, `Ord`, `Hash` and `Debug` (which lists the names of the single-bit flags present, followed by any remaining bits in hexadecimal), as well as the `|`, `&`, `^` and `-` operators, and methods for querying and modifying the set.
+
+## Example
+
+```
+#[macro_use] extern crate conv;
+
+flag_set! {
+    /// The status register of a serial port.
+    pub struct Status: u8 {
+        const READY = 0b0001;
+        const BUSY = 0b0010;
+        const ERROR = 0b1000;
+    }
+}
+
+fn main() {
+    use conv::{ApproxInto, TryFrom, Unrepresentable, ValueInto, Wrapping};
+
+    let status = Status::try_from(0b1001).unwrap();
+    assert!(status.contains(Status::READY | Status::ERROR));
+    assert_eq!(format!("{:?}", status), "Status(READY | ERROR)");
+    assert_eq!(Status::try_from(0b0101), Err(Unrepresentable(0b0101)));
+
+    let status: Status = ApproxInto::<_, Wrapping>::approx_into(0b0101u8).unwrap();
+    assert_eq!(status, Status::READY);
+
+    let bits: u8 = (Status::BUSY | Status::ERROR).value_into().unwrap();
+    assert_eq!(bits, 0b1010);
+}
+```
+
+The constants of a private set are not visible outside its module:
+
+```compile_fail,E0624
+#[macro_use] extern crate conv;
+
+mod port {
+    flag_set! {
+        struct Status: u8 {
+            const READY = 0b0001;
+        }
+    }
+
+    pub type Port = Status;
+}
+
+fn main() {
+    let _ = port::Port::READY;
+}
+```
 */
 
 /**
@@ -146,3 +221,166 @@ macro_rules! TryFrom {
         }
     };
 }
+
+/**
+See the documentation for the [`macros`](./macros/index.html#flag_set!) module for details.
+*/
+#[macro_export]
+macro_rules! flag_set {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident: $bits:ty {
+            $(
+                $(#[$flag_attr:meta])*
+                const $flag:ident = $value:expr;
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        $vis struct $name($bits);
+
+        impl $name {
+            $(
+                $(#[$flag_attr])*
+                $vis const $flag: $name = $name($value);
+            )*
+        }
+
+        // The helpers are generated for every flag set, so a private set need not use them all.
+        #[allow(dead_code)]
+        impl $name {
+            /// Returns the set containing no flags.
+            #[inline]
+            pub fn empty() -> $name {
+                $name(0)
+            }
+
+            /// Returns the set containing every named flag.
+            #[inline]
+            pub fn all() -> $name {
+                $name(0 $(| $value)*)
+            }
+
+            /// Returns the bits of the set.
+            #[inline]
+            pub fn bits(self) -> $bits {
+                self.0
+            }
+
+            /// Returns `true` if the set contains no flags.
+            #[inline]
+            pub fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            /// Returns `true` if every flag in `other` is also in this set.
+            #[inline]
+            pub fn contains(self, other: $name) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Adds the flags in `other` to this set.
+            #[inline]
+            pub fn insert(&mut self, other: $name) {
+                self.0 |= other.0;
+            }
+
+            /// Removes the flags in `other` from this set.
+            #[inline]
+            pub fn remove(&mut self, other: $name) {
+                self.0 &= !other.0;
+            }
+        }
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                fmt.write_str(concat!(stringify!($name), "("))?;
+                let mut first = true;
+                let mut rest = self.0;
+                // Composite flags are left out, as their bits are listed individually.
+                $(
+                    if $name::$flag.0.count_ones() == 1 && rest & $name::$flag.0 != 0 {
+                        if !first {
+                            fmt.write_str(" | ")?;
+                        }
+                        first = false;
+                        rest &= !$name::$flag.0;
+                        fmt.write_str(stringify!($flag))?;
+                    }
+                )*
+                if rest != 0 {
+                    if !first {
+                        fmt.write_str(" | ")?;
+                    }
+                    first = false;
+                    write!(fmt, "{:#x}", rest)?;
+                }
+                if first {
+                    fmt.write_str("empty")?;
+                }
+                fmt.write_str(")")
+            }
+        }
+
+        impl ::std::ops::BitOr for $name {
+            type Output = $name;
+            #[inline]
+            fn bitor(self, other: $name) -> $name {
+                $name(self.0 | other.0)
+            }
+        }
+
+        impl ::std::ops::BitAnd for $name {
+            type Output = $name;
+            #[inline]
+            fn bitand(self, other: $name) -> $name {
+                $name(self.0 & other.0)
+            }
+        }
+
+        impl ::std::ops::BitXor for $name {
+            type Output = $name;
+            #[inline]
+            fn bitxor(self, other: $name) -> $name {
+                $name(self.0 ^ other.0)
+            }
+        }
+
+        impl ::std::ops::Sub for $name {
+            type Output = $name;
+            #[inline]
+            fn sub(self, other: $name) -> $name {
+                $name(self.0 & !other.0)
+            }
+        }
+
+        impl $crate::TryFrom<$bits> for $name {
+            type Err = $crate::errors::Unrepresentable<$bits>;
+            #[inline]
+            fn try_from(src: $bits) -> Result<$name, Self::Err> {
+                if src & !$name::all().0 == 0 {
+                    Ok($name(src))
+                } else {
+                    Err($crate::errors::Unrepresentable(src))
+                }
+            }
+        }
+
+        impl $crate::ApproxFrom<$bits, $crate::Wrapping> for $name {
+            type Err = $crate::errors::NoError;
+            #[inline]
+            fn approx_from(src: $bits) -> Result<$name, Self::Err> {
+                Ok($name(src & $name::all().0))
+            }
+        }
+
+        impl $crate::ValueFrom<$name> for $bits {
+            type Err = $crate::errors::NoError;
+            #[inline]
+            fn value_from(src: $name) -> Result<$bits, Self::Err> {
+                Ok(src.0)
+            }
+        }
+    };
+}
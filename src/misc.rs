@@ -16,7 +16,7 @@ pub trait Saturated {
 }
 
 item_for_each! {
-    (i8), (i16), (i32), (i64), (u8), (u16), (u32), (u64), (isize), (usize) => {
+    (i8), (i16), (i32), (i64), (i128), (u8), (u16), (u32), (u64), (u128), (isize), (usize) => {
         ($ity:ident) => {
             impl Saturated for $ity {
                 #[inline] fn saturated_max() -> Self { ::std::$ity::MAX }
@@ -26,6 +26,12 @@ item_for_each! {
     }
 }
 
+impl<T, const MIN: i128, const MAX: i128> Saturated for ::Ranged<T, MIN, MAX>
+where T: ::ranged::RangedRepr {
+    #[inline] fn saturated_max() -> Self { ::Ranged::max_value() }
+    #[inline] fn saturated_min() -> Self { ::Ranged::min_value() }
+}
+
 /**
 This trait indicates that a type has an "invalid" sentinel value.
 
@@ -69,3 +75,86 @@ item_for_each! {
         };
     }
 }
+
+/**
+This trait describes the smallest and largest values of a type that a conversion from `Src` accepts, for use in error messages.
+
+This is used by the `errors::WithDetail` extension trait.  Either bound may be omitted if the type does not have a meaningful one.
+
+For most types, these are the type's own `MIN` and `MAX`.  Converting an integer into a float, however, only accepts integers the float can represent exactly, so the bounds are those given by [`ConvBounds`](../trait.ConvBounds.html).
+*/
+pub trait DescribeBounds<Src> {
+    /// Returns the smallest value accepted, formatted for display.
+    fn describe_min() -> Option<String>;
+
+    /// Returns the largest value accepted, formatted for display.
+    fn describe_max() -> Option<String>;
+
+    /// Returns whether the bounds are the type's own `MIN` and `MAX`, rather than ones particular to converting from `Src`.
+    #[inline]
+    fn bounds_are_limits() -> bool { true }
+}
+
+item_for_each! {
+    (i8), (i16), (i32), (i64), (i128), (u8), (u16), (u32), (u64), (u128), (isize), (usize) => {
+        ($ity:ident) => {
+            impl<Src> DescribeBounds<Src> for $ity {
+                #[inline] fn describe_min() -> Option<String> { Some($ity::MIN.to_string()) }
+                #[inline] fn describe_max() -> Option<String> { Some($ity::MAX.to_string()) }
+            }
+        };
+    }
+}
+
+macro_rules! describe_float_bounds {
+    ($fty:ident: float $($src:ident)*) => {
+        $(
+            impl DescribeBounds<$src> for $fty {
+                #[inline] fn describe_min() -> Option<String> { Some(format!("{:e}", $fty::MIN)) }
+                #[inline] fn describe_max() -> Option<String> { Some(format!("{:e}", $fty::MAX)) }
+            }
+        )*
+    };
+
+    ($fty:ident: $($src:ident)*) => {
+        $(
+            impl DescribeBounds<$src> for $fty {
+                #[inline] fn describe_min() -> Option<String> { Some(<$fty as ::ConvBounds<$src>>::min_src().to_string()) }
+                #[inline] fn describe_max() -> Option<String> { Some(<$fty as ::ConvBounds<$src>>::max_src().to_string()) }
+                #[inline] fn bounds_are_limits() -> bool { false }
+            }
+        )*
+    };
+}
+
+describe_float_bounds! { f32: i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+describe_float_bounds! { f64: i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+describe_float_bounds! { f32: float f32 f64 }
+describe_float_bounds! { f64: float f32 f64 }
+
+item_for_each! {
+    (NonZeroI8), (NonZeroI16), (NonZeroI32), (NonZeroI64), (NonZeroI128), (NonZeroIsize),
+    (NonZeroU8), (NonZeroU16), (NonZeroU32), (NonZeroU64), (NonZeroU128), (NonZeroUsize) => {
+        ($nz:ident) => {
+            impl<Src> DescribeBounds<Src> for ::std::num::$nz {
+                #[inline] fn describe_min() -> Option<String> { Some(::std::num::$nz::MIN.to_string()) }
+                #[inline] fn describe_max() -> Option<String> { Some(::std::num::$nz::MAX.to_string()) }
+            }
+        };
+    }
+}
+
+impl<Src> DescribeBounds<Src> for char {
+    #[inline] fn describe_min() -> Option<String> { Some(format!("{:?}", '\0')) }
+    #[inline] fn describe_max() -> Option<String> { Some(format!("{:?}", ::std::char::MAX)) }
+}
+
+impl<Src> DescribeBounds<Src> for bool {
+    #[inline] fn describe_min() -> Option<String> { None }
+    #[inline] fn describe_max() -> Option<String> { None }
+}
+
+impl<Src, T, const MIN: i128, const MAX: i128> DescribeBounds<Src> for ::Ranged<T, MIN, MAX> {
+    #[inline] fn describe_min() -> Option<String> { Some(MIN.to_string()) }
+    #[inline] fn describe_max() -> Option<String> { Some(MAX.to_string()) }
+}
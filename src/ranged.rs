@@ -0,0 +1,166 @@
+/*!
+This module defines a bounded integer type, whose range is fixed at compile time.
+
+See the [`Ranged`](./struct.Ranged.html) type.
+*/
+
+use std::fmt::{self, Display};
+use {TryFrom, ValueFrom};
+use errors::RangeError;
+
+/**
+An integer of type `T` which is guaranteed to be within the inclusive range `MIN..=MAX`.
+
+This is intended to make it simple to define integer-like domain types, such as a percentage:
+
+```
+# use conv::*;
+type Percent = Ranged<u8, 0, 100>;
+
+assert_eq!(Percent::value_from(42i32).map(Ranged::get), Ok(42));
+assert_eq!(Percent::value_from(-1i32), Err(RangeError::NegOverflow(-1)));
+assert_eq!(Percent::value_from(101u64), Err(RangeError::PosOverflow(101)));
+assert_eq!(300i32.value_as::<Percent>().unwrap_or_saturate().get(), 100);
+```
+
+`Ranged` implements `ValueFrom` and `TryFrom` for every primitive integer type, failing with `RangeError::NegOverflow` for values below `MIN`, and `RangeError::PosOverflow` for values above `MAX`.  It can also be converted into every primitive integer type with `ValueFrom`; this uses the same conversion as the underlying type `T`, so it cannot fail if the destination type can hold every value of `T`.  Finally, it implements [`Saturated`](../misc/trait.Saturated.html), saturating to `MIN` and `MAX`.
+
+The bounds are given as `i128`s, as the type of a const parameter cannot depend on another parameter.  They must lie within the range of `T`, and `MIN` must not be greater than `MAX`; otherwise, using the type will fail to compile.
+*/
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Ranged<T, const MIN: i128, const MAX: i128>(T);
+
+impl<T, const MIN: i128, const MAX: i128> Ranged<T, MIN, MAX> where T: RangedRepr {
+    // Evaluated when the type is used, rejecting invalid bounds at compile time.
+    const BOUNDS_OK: bool = {
+        assert!(
+            T::LOWEST <= MIN && MIN <= MAX && MAX <= T::HIGHEST,
+            "the bounds of a `Ranged` must be in order, and within the range of the underlying type"
+        );
+        true
+    };
+
+    /// Creates a new value, if `v` is within the bounds.
+    #[inline]
+    pub fn new(v: T) -> Option<Self> {
+        let _ = Self::BOUNDS_OK;
+        match v.to_i128() {
+            Some(wide) if MIN <= wide && wide <= MAX => Some(Ranged(v)),
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying value.
+    #[inline]
+    pub fn get(self) -> T {
+        self.0
+    }
+
+    /// Returns the smallest value of this type.
+    #[inline]
+    pub fn min_value() -> Self {
+        let _ = Self::BOUNDS_OK;
+        Ranged(T::from_i128(MIN))
+    }
+
+    /// Returns the largest value of this type.
+    #[inline]
+    pub fn max_value() -> Self {
+        let _ = Self::BOUNDS_OK;
+        Ranged(T::from_i128(MAX))
+    }
+}
+
+impl<T, const MIN: i128, const MAX: i128> Display for Ranged<T, MIN, MAX> where T: Display {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0.fmt(fmt)
+    }
+}
+
+/**
+This trait is implemented for the primitive integer types that can be used as the underlying type of a [`Ranged`](./struct.Ranged.html).
+*/
+pub trait RangedRepr: Copy {
+    /// The smallest value of the type, as an `i128`.
+    const LOWEST: i128;
+
+    /// The largest value of the type as an `i128`, or `i128::MAX` if it is larger than that.
+    const HIGHEST: i128;
+
+    /// Widens the value to an `i128`, if it is no larger than `HIGHEST`.
+    fn to_i128(self) -> Option<i128>;
+
+    /// Narrows an `i128` to this type.  The value must be between `LOWEST` and `HIGHEST`.
+    fn from_i128(v: i128) -> Self;
+}
+
+macro_rules! ranged_repr {
+    ($($ity:ident),*) => {
+        $(
+            impl RangedRepr for $ity {
+                const LOWEST: i128 = $ity::MIN as i128;
+                const HIGHEST: i128 = if $ity::MAX as u128 > i128::MAX as u128 {
+                    i128::MAX
+                } else {
+                    $ity::MAX as i128
+                };
+
+                #[inline]
+                fn to_i128(self) -> Option<i128> {
+                    <i128 as ValueFrom<$ity>>::value_from(self).ok()
+                }
+
+                #[inline]
+                fn from_i128(v: i128) -> $ity {
+                    v as $ity
+                }
+            }
+        )*
+    };
+}
+
+ranged_repr! { i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize }
+
+macro_rules! ranged_conv {
+    ($($repr:ident),* => $srcs:tt) => {
+        $(ranged_conv! { @ $repr => $srcs })*
+    };
+
+    (@ $repr:ident => ($($src:ident),*)) => {
+        $(
+            impl<const MIN: i128, const MAX: i128> ValueFrom<$src> for Ranged<$repr, MIN, MAX> {
+                type Err = RangeError<$src>;
+                #[inline]
+                fn value_from(src: $src) -> Result<Self, Self::Err> {
+                    let _ = Self::BOUNDS_OK;
+                    match src.to_i128() {
+                        Some(wide) if wide < MIN => Err(RangeError::NegOverflow(src)),
+                        Some(wide) if wide <= MAX => Ok(Ranged(<$repr as RangedRepr>::from_i128(wide))),
+                        _ => Err(RangeError::PosOverflow(src)),
+                    }
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> TryFrom<$src> for Ranged<$repr, MIN, MAX> {
+                type Err = RangeError<$src>;
+                #[inline]
+                fn try_from(src: $src) -> Result<Self, Self::Err> {
+                    ValueFrom::value_from(src)
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> ValueFrom<Ranged<$repr, MIN, MAX>> for $src {
+                type Err = <$src as ValueFrom<$repr>>::Err;
+                #[inline]
+                fn value_from(src: Ranged<$repr, MIN, MAX>) -> Result<$src, Self::Err> {
+                    <$src as ValueFrom<$repr>>::value_from(src.0)
+                }
+            }
+        )*
+    };
+}
+
+ranged_conv! {
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+    => (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize)
+}
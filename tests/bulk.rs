@@ -0,0 +1,244 @@
+extern crate conv;
+
+use std::fmt::Debug;
+use conv::*;
+use conv::bulk::{ApproxFromSlice, ValueFromSlice, approx_slice, approx_slice_by, value_slice};
+
+/// A simple xorshift generator, so the sample data is reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn next_i128(&mut self) -> i128 {
+        ((self.next() as u128) << 64 | self.next() as u128) as i128
+    }
+
+    fn next_unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Sample data, as a number of independent runs.  Each run is converted separately, so that an early failure doesn't hide the rest of the samples.
+trait Samples: Sized {
+    fn samples() -> Vec<Vec<Self>>;
+}
+
+macro_rules! int_samples {
+    ($($ty:ident)*) => {
+        $(
+            impl Samples for $ty {
+                fn samples() -> Vec<Vec<$ty>> {
+                    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+                    let mut v: Vec<_> = [$ty::MIN, $ty::MAX, 0, 1].iter().map(|&e| vec![e]).collect();
+                    // Runs of values of a similar magnitude, so that whole chunks are either in or out of range.
+                    for shift in (0..128).step_by(4) {
+                        v.push((0..150).map(|_| (rng.next_i128() >> shift) as $ty).collect());
+                        v.push((0..150).map(|_| ((rng.next_i128() as u128) >> shift) as $ty).collect());
+                    }
+                    v
+                }
+            }
+        )*
+    };
+}
+
+int_samples! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+macro_rules! float_samples {
+    ($($ty:ident)*) => {
+        $(
+            impl Samples for $ty {
+                fn samples() -> Vec<Vec<$ty>> {
+                    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+                    let edges = [
+                        $ty::NAN, $ty::INFINITY, $ty::NEG_INFINITY, 0.0, -0.0,
+                        0.5, -0.5, 1.5, -1.5, 2.5, -2.5, 127.5, 128.0, -128.5, -129.0,
+                        255.4, 255.5, 256.0, -0.9, 65_535.5, 65_536.0,
+                        2_147_483_647.5, 2_147_483_648.0, -2_147_483_648.5,
+                        4_294_967_296.0, 9_223_372_036_854_775_808.0, -9_223_372_036_854_775_808.0,
+                        18_446_744_073_709_551_616.0, -18_446_744_073_709_551_616.0,
+                        170_141_183_460_469_231_731_687_303_715_884_105_728.0,
+                        -170_141_183_460_469_231_731_687_303_715_884_105_728.0, $ty::MAX, $ty::MIN,
+                        0.49999997, -0.49999997, 3.5, -3.5, 4.5, -4.5,
+                        8_388_607.5, -8_388_607.5, 8_388_608.0, 16_777_215.0,
+                        4_503_599_627_370_495.5, -4_503_599_627_370_495.5, 4_503_599_627_370_497.0,
+                    ];
+                    let mut v: Vec<_> = edges.iter().map(|&e| vec![e]).collect();
+                    for exp in -2..130 {
+                        let scale = (2.0f64).powi(exp);
+                        v.push((0..150).map(|_| (rng.next_unit() * scale) as $ty).collect());
+                        v.push((0..150).map(|_| ((rng.next_unit() - 0.5) * scale) as $ty).collect());
+                    }
+                    v
+                }
+            }
+        )*
+    };
+}
+
+float_samples! { f32 f64 }
+
+/// Compares the bulk conversion against converting each element individually.
+fn check<Src, Dst, E, B, S>(runs: &[Vec<Src>], bulk: B, scalar: S)
+where
+    Src: Copy + Debug,
+    Dst: Copy + Debug + Default,
+    E: Debug,
+    B: Fn(&[Src], &mut [Dst]) -> Result<(), ElementError<E>>,
+    S: Fn(Src) -> Result<Dst, E>,
+{
+    // Check from a few different offsets, so failures land in different parts of a chunk.
+    for (run, &start) in runs.iter().flat_map(|r| [0, 1, 29, 63].iter().map(move |s| (r, s))) {
+        if start >= run.len() && start != 0 {
+            continue;
+        }
+        let src = &run[start..];
+        let mut dst = vec![Dst::default(); src.len()];
+        let res = bulk(src, &mut dst);
+
+        let mut expected = vec![Dst::default(); src.len()];
+        let mut expected_err = None;
+        for (i, &s) in src.iter().enumerate() {
+            match scalar(s) {
+                Ok(v) => expected[i] = v,
+                Err(e) => { expected_err = Some((i, e)); break; }
+            }
+        }
+
+        match (res, expected_err) {
+            (Ok(()), None) => (),
+            (Err(e), Some((index, err))) => {
+                assert_eq!(e.index, index, "{:?}", src[index]);
+                assert_eq!(format!("{:?}", e.error), format!("{:?}", err), "{:?}", src[index]);
+            },
+            (res, expected_err) => panic!("expected {:?}, got {:?}", expected_err, res),
+        }
+        assert_eq!(format!("{:?}", dst), format!("{:?}", expected));
+    }
+}
+
+macro_rules! check_value {
+    ($($src:ident => ($($dst:ident)*);)*) => {
+        $(
+            {
+                let src = <$src as Samples>::samples();
+                $(
+                    check::<$src, $dst, _, _, _>(&src,
+                        <$dst as ValueFromSlice<$src>>::value_from_slice,
+                        <$dst as ValueFrom<$src>>::value_from);
+                )*
+            }
+        )*
+    };
+}
+
+macro_rules! check_approx {
+    ($($src:ident => ($($dst:ident)*);)*) => {
+        $(
+            {
+                let src = <$src as Samples>::samples();
+                $(
+                    check_approx!(@ src, $src, $dst, DefaultApprox);
+                    check_approx!(@ src, $src, $dst, RoundToNearest);
+                    check_approx!(@ src, $src, $dst, RoundHalfEven);
+                    check_approx!(@ src, $src, $dst, RoundToNegInf);
+                    check_approx!(@ src, $src, $dst, RoundToPosInf);
+                    check_approx!(@ src, $src, $dst, RoundToZero);
+                    check_approx!(@ src, $src, $dst, Wrapping);
+                    check_approx!(@ src, $src, $dst, Saturating);
+                    check_approx!(@ src, $src, $dst, Saturating<RoundToNearest>);
+                    check_approx!(@ src, $src, $dst, Saturating<RoundHalfEven>);
+                    check_approx!(@ src, $src, $dst, Saturating<RoundToNegInf>);
+                    check_approx!(@ src, $src, $dst, Saturating<RoundToPosInf>);
+                    check_approx!(@ src, $src, $dst, Saturating<RoundToZero>);
+                )*
+            }
+        )*
+    };
+
+    (@ $samples:ident, $src:ident, $dst:ident, $scheme:ty) => {
+        check::<$src, $dst, _, _, _>(&$samples,
+            <$dst as ApproxFromSlice<$src, $scheme>>::approx_from_slice,
+            <$dst as ApproxFrom<$src, $scheme>>::approx_from);
+    };
+}
+
+#[test]
+fn test_value_matches_scalar() {
+    check_value! {
+        i8 => (f32 f64);
+        i16 => (f32 f64);
+        i32 => (f32 f64);
+        i64 => (f32 f64);
+        i128 => (f32 f64);
+        isize => (f32 f64);
+        u8 => (f32 f64);
+        u16 => (f32 f64);
+        u32 => (f32 f64);
+        u64 => (f32 f64);
+        u128 => (f32 f64);
+        usize => (f32 f64);
+        f32 => (f32 f64);
+        f64 => (f64);
+    }
+}
+
+#[test]
+fn test_approx_matches_scalar() {
+    check_approx! {
+        f32 => (i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+        f64 => (i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+    }
+}
+
+#[test]
+fn test_bulk() {
+    let src: Vec<f64> = (0..200).map(|i| i as f64 * 1.5 - 100.0).collect();
+    let mut dst = vec![0i16; 200];
+    approx_slice_by::<RoundToNearest, _, _>(&src, &mut dst).unwrap();
+    assert_eq!(dst[0], -100);
+    assert_eq!(dst[199], 199);
+
+    let mut dst = vec![0u8; 200];
+    let err = approx_slice(&src, &mut dst).unwrap_err();
+    assert_eq!(err.index, 0);
+    assert_eq!(err.error, FloatError::NegOverflow(-100.0));
+
+    // 2^63 is `i64::MAX as f64`, but is still out of range.
+    let mut src: Vec<f64> = (0..200).map(|i| i as f64).collect();
+    src[70] = 2.0f64.powi(63);
+    let mut dst = vec![0i64; 200];
+    let err = approx_slice(&src, &mut dst).unwrap_err();
+    assert_eq!(err.index, 70);
+    assert_eq!(err.error, FloatError::PosOverflow(2.0f64.powi(63)));
+    let mut dst = vec![0u32; 200];
+    src[70] = 2.0f64.powi(32);
+    let err = approx_slice_by::<RoundHalfEven, _, _>(&src, &mut dst).unwrap_err();
+    assert_eq!(err.index, 70);
+    assert_eq!(err.error, FloatError::PosOverflow(2.0f64.powi(32)));
+    let src = [1.0f32, 2.0f32.powi(31)];
+    let mut dst = [0i32; 2];
+    let err = approx_slice_by::<RoundToZero, _, _>(&src, &mut dst).unwrap_err();
+    assert_eq!((err.index, err.error), (1, FloatError::PosOverflow(2.0f32.powi(31))));
+
+    let src: Vec<i32> = (0..300).map(|i| 16_776_960 + i).collect();
+    let mut dst = vec![0f32; 300];
+    let err = value_slice(&src, &mut dst).unwrap_err();
+    assert_eq!(err.index, 257);
+    assert_eq!(err.error, RangeError::PosOverflow(16_777_217));
+    assert_eq!(dst[256], 16_777_216.0);
+    assert_eq!(dst[257], 0.0);
+}
+
+#[test]
+#[should_panic]
+fn test_bulk_length_mismatch() {
+    let mut dst = [0f32; 2];
+    let _ = value_slice(&[1i32, 2, 3], &mut dst);
+}
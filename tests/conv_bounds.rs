@@ -0,0 +1,82 @@
+extern crate conv;
+
+use conv::*;
+
+macro_rules! check_bounds {
+    ($src:ty=> $($dst:ty),*) => {
+        $(
+            {
+                let min = <$dst as ConvBounds<$src>>::min_src();
+                let max = <$dst as ConvBounds<$src>>::max_src();
+                assert!(<$dst as ValueFrom<$src>>::value_from(min).is_ok(),
+                    "{} -> {}: min", stringify!($src), stringify!($dst));
+                assert!(<$dst as ValueFrom<$src>>::value_from(max).is_ok(),
+                    "{} -> {}: max", stringify!($src), stringify!($dst));
+                if let Some(below) = min.checked_sub(1) {
+                    assert!(<$dst as ValueFrom<$src>>::value_from(below).is_err(),
+                        "{} -> {}: below min", stringify!($src), stringify!($dst));
+                }
+                if let Some(above) = max.checked_add(1) {
+                    assert!(<$dst as ValueFrom<$src>>::value_from(above).is_err(),
+                        "{} -> {}: above max", stringify!($src), stringify!($dst));
+                }
+            }
+        )*
+    };
+}
+
+#[test]
+fn test_int_bounds() {
+    check_bounds!(i8=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    check_bounds!(i16=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    check_bounds!(i32=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    check_bounds!(i64=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    check_bounds!(i128=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    check_bounds!(isize=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    check_bounds!(u8=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    check_bounds!(u16=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    check_bounds!(u32=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    check_bounds!(u64=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    check_bounds!(u128=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    check_bounds!(usize=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+    assert_eq!(<u8 as ConvBounds<i32>>::min_src(), 0);
+    assert_eq!(<u8 as ConvBounds<i32>>::max_src(), 255);
+    assert_eq!(<i8 as ConvBounds<u64>>::max_src(), 127);
+
+    // `i64` -> `usize` keeps the same error type on every target, even where it cannot overflow upward.
+    let r: Result<usize, RangeError<i64>> = usize::value_from(-1i64);
+    assert_eq!(r, Err(RangeError::NegOverflow(-1)));
+    #[cfg(target_pointer_width = "64")]
+    {
+        assert_eq!(usize::value_from(i64::MAX), Ok(i64::MAX as usize));
+        assert_eq!(<usize as ConvBounds<i64>>::max_src(), i64::MAX);
+    }
+}
+
+#[test]
+fn test_int_to_float_bounds() {
+    check_bounds!(i8=> f32, f64);
+    check_bounds!(i16=> f32, f64);
+    check_bounds!(i32=> f32, f64);
+    check_bounds!(i64=> f32, f64);
+    check_bounds!(i128=> f32, f64);
+    check_bounds!(isize=> f32, f64);
+    check_bounds!(u8=> f32, f64);
+    check_bounds!(u16=> f32, f64);
+    check_bounds!(u32=> f32, f64);
+    check_bounds!(u64=> f32, f64);
+    check_bounds!(u128=> f32, f64);
+    check_bounds!(usize=> f32, f64);
+
+    assert_eq!(<f32 as ConvBounds<i64>>::min_src(), -16_777_216);
+    assert_eq!(<f32 as ConvBounds<i64>>::max_src(), 16_777_216);
+    assert_eq!(<f64 as ConvBounds<u64>>::min_src(), 0);
+}
+
+#[test]
+fn test_float_bounds() {
+    assert_eq!(<f64 as ConvBounds<f32>>::min_src(), f32::NEG_INFINITY);
+    assert_eq!(<f64 as ConvBounds<f32>>::max_src(), f32::INFINITY);
+    assert_eq!(<f32 as ConvBounds<f32>>::max_src(), f32::INFINITY);
+}
@@ -0,0 +1,58 @@
+extern crate conv;
+
+use conv::prelude::*;
+use conv::{FloatError, RangeError, RoundToNearest};
+
+#[test]
+fn test_map_value_as() {
+    let v: Vec<_> = vec![1i32, -1, 300].into_iter().map_value_as::<u8>().collect();
+    assert_eq!(v, vec![Ok(1), Err(RangeError::NegOverflow(-1)), Err(RangeError::PosOverflow(300))]);
+
+    let v: Result<Vec<u16>, _> = vec![1i32, 2, 3].into_iter().map_value_as().collect();
+    assert_eq!(v, Ok(vec![1, 2, 3]));
+
+    let it = vec![1i32, 2, 3].into_iter().map_value_as::<u8>();
+    assert_eq!(it.len(), 3);
+    let v: Vec<_> = it.rev().collect();
+    assert_eq!(v, vec![Ok(3), Ok(2), Ok(1)]);
+}
+
+#[test]
+fn test_map_approx_as() {
+    let v: Vec<_> = vec![1.6f32, -1.0].into_iter().map_approx_as::<u8>().collect();
+    assert_eq!(v, vec![Ok(1), Err(FloatError::NegOverflow(-1.0))]);
+
+    let v: Vec<_> = vec![1.6f32, 2.5].into_iter().map_approx_as_by::<u8, RoundToNearest>().collect();
+    assert_eq!(v, vec![Ok(2), Ok(3)]);
+}
+
+#[test]
+fn test_saturating_as() {
+    let v: Vec<i8> = vec![-1000i32, -5, 5, 1000].into_iter().saturating_as().collect();
+    assert_eq!(v, vec![-128, -5, 5, 127]);
+
+    let v: Vec<u8> = vec![-1000i32, 1000].into_iter().rev().saturating_as().collect();
+    assert_eq!(v, vec![255, 0]);
+}
+
+#[test]
+fn test_skip_unconvertible() {
+    let v: Vec<u8> = vec![-1i64, 1, 256, 2, 1 << 40].into_iter().skip_unconvertible().collect();
+    assert_eq!(v, vec![1, 2]);
+
+    let v: Vec<u8> = vec![-1i64, 1, 256, 2].into_iter().skip_unconvertible().rev().collect();
+    assert_eq!(v, vec![2, 1]);
+
+    let mut it = vec![-1i64, -2].into_iter().skip_unconvertible::<u8>();
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn test_value_failures() {
+    let errs: Vec<_> = vec![0u32, 70_000, 1, 80_000].into_iter().value_failures::<u16>().collect();
+    assert_eq!(errs.len(), 2);
+    assert_eq!((errs[0].index, errs[0].error), (1, conv::PosOverflow(70_000)));
+    assert_eq!((errs[1].index, errs[1].error), (3, conv::PosOverflow(80_000)));
+
+    assert_eq!(vec![1u32, 2].into_iter().value_failures::<u16>().count(), 0);
+}
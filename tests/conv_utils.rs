@@ -11,6 +11,47 @@ fn test_approx() {
     assert_eq!((1.5f32).approx_as_by::<i32, DefaultApprox>(), Ok(1));
 }
 
+#[test]
+fn test_check() {
+    use conv::{GeneralErrorKind as K, RoundToNearest};
+    #[derive(PartialEq, Debug)] enum ItAintRight { BabeNo, NoNo }
+    TryFrom! { (u8) enum ItAintRight { BabeNo, NoNo } }
+
+    let x = 300i32;
+    assert_eq!(u8::can_value_from(&x), Err(K::PosOverflow));
+    assert_eq!(u8::can_value_from(&-1i32), Err(K::NegOverflow));
+    assert_eq!(u16::can_value_from(&x), Ok(()));
+    assert_eq!(x, 300);
+
+    assert_eq!(i32::can_approx_from(&1.5f32), Ok(()));
+    assert_eq!(u8::can_approx_from(&::std::f32::NAN), Err(K::Unrepresentable));
+    assert_eq!(u8::can_approx_from_by::<RoundToNearest>(&255.4f32), Ok(()));
+    assert_eq!(u8::can_approx_from_by::<RoundToNearest>(&255.5f32), Err(K::PosOverflow));
+
+    assert_eq!(ItAintRight::can_try_from(&1u8), Ok(()));
+    assert_eq!(ItAintRight::can_try_from(&2u8), Err(K::Unrepresentable));
+}
+
+#[test]
+fn test_option() {
+    use conv::{FloatError, RangeError, RoundToNearest, Unrepresentable};
+    #[derive(PartialEq, Debug)] enum ItAintRight { BabeNo, NoNo }
+    TryFrom! { (u8) enum ItAintRight { BabeNo, NoNo } }
+
+    assert_eq!(Some(123i32).opt_value_as::<u8>(), Ok(Some(123)));
+    assert_eq!(Some(-1i32).opt_value_as::<u8>(), Err(RangeError::NegOverflow(-1)));
+    assert_eq!(None::<i32>.opt_value_as::<u8>(), Ok(None));
+
+    assert_eq!(Some(1.5f32).opt_approx_as::<u8>(), Ok(Some(1)));
+    assert_eq!(Some(1.5f32).opt_approx_as_by::<u8, RoundToNearest>(), Ok(Some(2)));
+    assert_eq!(Some(-1.5f32).opt_approx_as::<u8>(), Err(FloatError::NegOverflow(-1.5)));
+    assert_eq!(None::<f32>.opt_approx_as_by::<u8, RoundToNearest>(), Ok(None));
+
+    assert_eq!(Some(1u8).opt_try_as::<ItAintRight>(), Ok(Some(ItAintRight::NoNo)));
+    assert_eq!(Some(2u8).opt_try_as::<ItAintRight>(), Err(Unrepresentable(2)));
+    assert_eq!(None::<u8>.opt_try_as::<ItAintRight>(), Ok(None));
+}
+
 #[test]
 fn test_into() {
     let v = "ABC".into_as::<Vec<u8>>();
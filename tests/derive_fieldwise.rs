@@ -0,0 +1,151 @@
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use std::any::Any;
+use std::fmt::Debug;
+use conv::*;
+use conv::errors::NamedFieldError;
+
+/// The layout of a header on the wire.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct WireHeader {
+    version: u8,
+    length: u64,
+    sequence: u32,
+    level: f64,
+}
+
+/// A more compact in-memory header.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct SmallHeader {
+    version: i8,
+    length: u16,
+    sequence: u32,
+    level: i16,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Fieldwise)]
+#[fieldwise(value_from(SmallHeader), value_into(WireHeader))]
+#[fieldwise(approx_from(WireHeader), schemes(DefaultApprox, Saturating))]
+struct Header {
+    version: u8,
+    length: u32,
+    #[fieldwise(scheme = Wrapping)]
+    sequence: u16,
+    #[fieldwise(scheme = RoundToNearest)]
+    level: i32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Fieldwise)]
+#[fieldwise(try_into(Options))]
+struct RawOptions {
+    code: u32,
+    enabled: u8,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Fieldwise)]
+#[fieldwise(value_into(RawOptions))]
+struct Options {
+    code: char,
+    enabled: bool,
+}
+
+/// Only the fields of the struct being converted into need to exist in the source.
+#[derive(Copy, Clone, Debug, PartialEq, Fieldwise)]
+#[fieldwise(try_from(RawOptions))]
+struct Toggle {
+    enabled: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Empty {}
+
+#[derive(Copy, Clone, Debug, PartialEq, Fieldwise)]
+#[fieldwise(value_from(Empty), approx_into(Empty), try_into(Empty))]
+struct AlsoEmpty {}
+
+#[derive(Copy, Clone, Debug, PartialEq, Fieldwise)]
+#[fieldwise(approx_into(SmallHeader))]
+#[fieldwise(schemes(DefaultApprox, Saturating))]
+struct Reading {
+    version: i64,
+    length: i32,
+    sequence: u32,
+    #[fieldwise(scheme = DefaultApprox)]
+    level: f64,
+}
+
+/// Unpacks the field, kind and value of a failed conversion.
+fn failed<T, V>(r: Result<T, NamedFieldError>) -> (&'static str, GeneralErrorKind, Option<V>)
+where T: Debug, V: Any + Copy {
+    let e = r.unwrap_err();
+    (e.field, e.kind(), e.value::<V>().cloned())
+}
+
+#[test]
+fn test_fieldwise_from() {
+    let small = SmallHeader { version: 1, length: 20, sequence: 7, level: -2 };
+    let header = Header { version: 1, length: 20, sequence: 7, level: -2 };
+    assert_eq!(Header::value_from(small).unwrap(), header);
+    assert_eq!(failed(Header::value_from(SmallHeader { version: -1, ..small })),
+        ("version", GeneralErrorKind::NegOverflow, Some(-1i8)));
+    assert_eq!(failed(Header::value_from(SmallHeader { sequence: 70000, ..small })),
+        ("sequence", GeneralErrorKind::PosOverflow, Some(70000u32)));
+
+    // Per-field schemes are used whichever scheme the struct is converted with.
+    let wire = WireHeader { version: 2, length: 1 << 20, sequence: 0x1_0005, level: 2.5 };
+    let expected = Header { version: 2, length: 1 << 20, sequence: 5, level: 3 };
+    assert_eq!(<Header as ApproxFrom<_>>::approx_from(wire).unwrap(), expected);
+    assert_eq!(<Header as ApproxFrom<_, Saturating>>::approx_from(wire).unwrap(), expected);
+    let long = WireHeader { length: 1 << 40, ..wire };
+    assert_eq!(failed(<Header as ApproxFrom<_>>::approx_from(long)),
+        ("length", GeneralErrorKind::PosOverflow, Some(1u64 << 40)));
+    assert_eq!(<Header as ApproxFrom<_, Saturating>>::approx_from(long).unwrap(), Header { length: !0, ..expected });
+
+    let raw = RawOptions { code: 0x41, enabled: 1 };
+    assert_eq!(Toggle::try_from(raw).unwrap(), Toggle { enabled: true });
+    assert_eq!(failed(Toggle::try_from(RawOptions { enabled: 2, ..raw })),
+        ("enabled", GeneralErrorKind::Unrepresentable, Some(2u8)));
+
+    assert_eq!(AlsoEmpty::value_from(Empty {}).unwrap(), AlsoEmpty {});
+}
+
+#[test]
+fn test_fieldwise_into() {
+    let header = Header { version: 3, length: 100, sequence: 9, level: -4 };
+    assert_eq!(WireHeader::value_from(header).unwrap(),
+        WireHeader { version: 3, length: 100, sequence: 9, level: -4.0 });
+
+    let reading = Reading { version: 1, length: 70000, sequence: 1, level: 2.0 };
+    assert_eq!(<SmallHeader as ApproxFrom<_, Saturating>>::approx_from(reading).unwrap(),
+        SmallHeader { version: 1, length: 0xffff, sequence: 1, level: 2 });
+    assert_eq!(failed(<SmallHeader as ApproxFrom<_>>::approx_from(reading)),
+        ("length", GeneralErrorKind::PosOverflow, Some(70000i32)));
+    assert_eq!(<SmallHeader as ApproxFrom<_, Saturating>>::approx_from(Reading { version: 300, ..reading }).unwrap(),
+        SmallHeader { version: 127, length: 0xffff, sequence: 1, level: 2 });
+    assert_eq!(failed(<SmallHeader as ApproxFrom<_, Saturating>>::approx_from(Reading { level: 1e6, ..reading })),
+        ("level", GeneralErrorKind::PosOverflow, Some(1e6f64)));
+
+    let raw = RawOptions { code: 0x41, enabled: 0 };
+    assert_eq!(Options::try_from(raw).unwrap(), Options { code: 'A', enabled: false });
+    assert_eq!(failed(Options::try_from(RawOptions { code: 0xd800, ..raw })),
+        ("code", GeneralErrorKind::Unrepresentable, Some(0xd800u32)));
+    assert_eq!(RawOptions::value_from(Options { code: 'z', enabled: true }).unwrap(), RawOptions { code: 0x7a, enabled: 1 });
+
+    assert_eq!(<Empty as ApproxFrom<_>>::approx_from(AlsoEmpty {}).unwrap(), Empty {});
+    assert_eq!(Empty::try_from(AlsoEmpty {}).unwrap(), Empty {});
+}
+
+#[test]
+fn test_fieldwise_error() {
+    let e = NamedFieldError::new("length", PosOverflow(70000i32));
+    assert_eq!(e.to_string(), "conversion of field `length` failed: conversion resulted in positive overflow");
+    assert_eq!(e.value::<i32>(), Some(&70000));
+    assert_eq!(e.value::<u32>(), None);
+    assert_eq!(GeneralErrorKind::from(e), GeneralErrorKind::PosOverflow);
+
+    let e = NamedFieldError::convert("code", 0x11_0000u32, char::try_from).unwrap_err();
+    assert_eq!(e.kind(), GeneralErrorKind::Unrepresentable);
+    assert_eq!(e.value::<u32>(), Some(&0x11_0000));
+    assert_eq!(NamedFieldError::convert("code", 0x41u32, char::try_from).unwrap(), 'A');
+}
@@ -0,0 +1,88 @@
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use conv::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Newtype)]
+#[conv(value_from(f32, u16, i32), approx_from(f64), value_into(f64), approx_into(i32, u8))]
+#[conv(schemes(DefaultApprox, RoundToNearest, RoundToZero, Saturating))]
+struct Meters(f64);
+
+#[derive(Copy, Clone, Debug, PartialEq, Newtype)]
+#[conv(try_from(u32, u8), try_into(char), value_into(u32, i64))]
+struct Letter {
+    ch: char,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Newtype)]
+#[conv(value_from(u8, i64), approx_from(f64), value_into(i32))]
+#[conv(validate = Percent::in_range)]
+struct Percent(u8);
+
+impl Percent {
+    fn in_range(v: &u8) -> bool {
+        *v <= 100
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Newtype)]
+#[conv(value_from(u8, i8), value_into(i64))]
+struct Wrapper<T>(T);
+
+#[test]
+fn test_newtype_from() {
+    assert_eq!(Meters::value_from(1.5f32), Ok(Meters(1.5)));
+    assert_eq!(Meters::value_from(7u16), Ok(Meters(7.0)));
+    let r: Result<Meters, NoError> = ValueFrom::value_from(-3i32);
+    assert_eq!(r, Ok(Meters(-3.0)));
+
+    assert_eq!(<Meters as ApproxFrom<_>>::approx_from(0.25f64), Ok(Meters(0.25)));
+    assert_eq!(<Meters as ApproxFrom<_, RoundToNearest>>::approx_from(-0.5f64), Ok(Meters(-0.5)));
+    let m: Meters = 12.0f64.approx_by::<Saturating>().unwrap();
+    assert_eq!(m, Meters(12.0));
+
+    assert_eq!(Letter::try_from(0x41u32), Ok(Letter { ch: 'A' }));
+    assert_eq!(Letter::try_from(0xd800u32), Err(Unrepresentable(0xd800)));
+    assert_eq!(Letter::try_from(0x61u8), Ok(Letter { ch: 'a' }));
+}
+
+#[test]
+fn test_newtype_into() {
+    assert_eq!(f64::value_from(Meters(2.5)), Ok(2.5));
+    assert_eq!(<i32 as ApproxFrom<_, RoundToNearest>>::approx_from(Meters(2.5)), Ok(3));
+    assert_eq!(<i32 as ApproxFrom<_, RoundToZero>>::approx_from(Meters(2.5)), Ok(2));
+    assert_eq!(<u8 as ApproxFrom<_>>::approx_from(Meters(-1.0)), Err(FloatError::NegOverflow(-1.0)));
+    assert_eq!(<u8 as ApproxFrom<_, Saturating>>::approx_from(Meters(300.0)), Ok(255));
+    let r: Result<u8, _> = Meters(9.75).approx_by::<RoundToZero>();
+    assert_eq!(r, Ok(9));
+
+    assert_eq!(char::try_from(Letter { ch: 'A' }), Ok('A'));
+    assert_eq!(u32::value_from(Letter { ch: 'B' }), Ok(0x42));
+    assert_eq!(Letter { ch: 'C' }.value_as::<i64>(), Ok(0x43));
+}
+
+#[test]
+fn test_newtype_validate() {
+    assert_eq!(Percent::value_from(100u8), Ok(Percent(100)));
+    assert_eq!(Percent::value_from(101u8), Err(GeneralError::Unrepresentable(101)));
+    assert_eq!(Percent::value_from(50i64), Ok(Percent(50)));
+    assert_eq!(Percent::value_from(-1i64), Err(GeneralError::NegOverflow(-1)));
+    assert_eq!(Percent::value_from(300i64), Err(GeneralError::PosOverflow(300)));
+    assert_eq!(Percent::value_from(200i64), Err(GeneralError::Unrepresentable(200)));
+    assert_eq!(<Percent as ApproxFrom<_>>::approx_from(99.5f64), Ok(Percent(99)));
+    assert_eq!(<Percent as ApproxFrom<_>>::approx_from(100.5f64), Ok(Percent(100)));
+    assert_eq!(<Percent as ApproxFrom<_>>::approx_from(101.0f64), Err(GeneralError::Unrepresentable(101.0)));
+    assert_eq!(<Percent as ApproxFrom<_>>::approx_from(f64::NAN).map_err(GeneralErrorKind::from),
+        Err(GeneralErrorKind::Unrepresentable));
+
+    // Validation does not apply to conversions out of the newtype.
+    assert_eq!(i32::value_from(Percent(42)), Ok(42));
+}
+
+#[test]
+fn test_newtype_generic() {
+    assert_eq!(Wrapper::<i16>::value_from(200u8), Ok(Wrapper(200i16)));
+    assert_eq!(Wrapper::<u16>::value_from(-1i8), Err(NegOverflow(-1)));
+    assert_eq!(i64::value_from(Wrapper(7u32)), Ok(7));
+    assert_eq!(i64::value_from(Wrapper(u64::MAX)), Err(PosOverflow(u64::MAX)));
+}
@@ -0,0 +1,201 @@
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use std::marker::PhantomData;
+use conv::{TryFrom, TryInto, Unrepresentable};
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[try_from(u8)]
+enum Get { Up, Down, AllAround }
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[try_from(u16, i64)]
+#[try_from(u8)]
+pub(crate) enum GottaGo {
+    GetAway,
+    Fast = 9000,
+    /// This show was stupid.
+    Faster,
+}
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[repr(i8)]
+pub enum Signed {
+    Low = -128,
+    Zero = 0,
+    High = 127,
+}
+
+const BASE: u32 = 1 << 20;
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[repr(u32)]
+enum Wide {
+    Small = 1,
+    Big = BASE,
+    Bigger,
+}
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[repr(u8)]
+enum Marked<'a, T: 'a> where T: Clone {
+    A,
+    #[try_from(skip)]
+    _Marker(PhantomData<&'a T>),
+    C = 5,
+    #[try_from(skip)]
+    #[allow(dead_code)]
+    D,
+    E,
+}
+
+#[test]
+fn test_derive_try_from() {
+    assert_eq!(Get::try_from(0u8), Ok(Get::Up));
+    assert_eq!(Get::try_from(1u8), Ok(Get::Down));
+    assert_eq!(Get::try_from(2u8), Ok(Get::AllAround));
+    assert_eq!(Get::try_from(3u8), Err(Unrepresentable(3u8)));
+
+    assert_eq!(GottaGo::try_from(0u16), Ok(GottaGo::GetAway));
+    assert_eq!(GottaGo::try_from(8999u16), Err(Unrepresentable(8999u16)));
+    assert_eq!(GottaGo::try_from(9000u16), Ok(GottaGo::Fast));
+    assert_eq!(GottaGo::try_from(9001i64), Ok(GottaGo::Faster));
+    assert_eq!(GottaGo::try_from(-9001i64), Err(Unrepresentable(-9001i64)));
+    assert_eq!(GottaGo::try_from(0u8), Ok(GottaGo::GetAway));
+    // 9000 must not be truncated to match a `u8`.
+    assert_eq!(GottaGo::try_from((9000 % 256) as u8), Err(Unrepresentable(40u8)));
+    let res: Result<GottaGo, _> = 9000u16.try_into();
+    assert_eq!(res, Ok(GottaGo::Fast));
+}
+
+#[test]
+fn test_derive_try_from_repr() {
+    assert_eq!(Signed::try_from(-128i8), Ok(Signed::Low));
+    assert_eq!(Signed::try_from(0i8), Ok(Signed::Zero));
+    assert_eq!(Signed::try_from(127i8), Ok(Signed::High));
+    assert_eq!(Signed::try_from(1i8), Err(Unrepresentable(1i8)));
+
+    assert_eq!(Wide::try_from(1u32), Ok(Wide::Small));
+    assert_eq!(Wide::try_from(BASE), Ok(Wide::Big));
+    assert_eq!(Wide::try_from(BASE + 1), Ok(Wide::Bigger));
+    assert_eq!(Wide::try_from(2u32), Err(Unrepresentable(2u32)));
+}
+
+#[test]
+fn test_derive_try_from_generic() {
+    type M = Marked<'static, u8>;
+    assert_eq!(M::try_from(0u8), Ok(Marked::A));
+    assert_eq!(M::try_from(1u8), Err(Unrepresentable(1u8)));
+    assert_eq!(M::try_from(5u8), Ok(Marked::C));
+    assert_eq!(M::try_from(6u8), Err(Unrepresentable(6u8)));
+    assert_eq!(M::try_from(7u8), Ok(Marked::E));
+}
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[try_from(&str, String)]
+enum Exact {
+    Alpha,
+    #[try_from(alias = "b", alias = "bravo")]
+    Beta,
+}
+
+#[derive(Clone, Debug, PartialEq, TryFrom)]
+#[try_from(u8, &'static str, case_insensitive)]
+#[repr(u8)]
+enum Loose<T> {
+    #[try_from(alias = "on")]
+    Yes = 1,
+    #[try_from(alias = "OFF")]
+    No = 0,
+    #[try_from(skip)]
+    Other(PhantomData<T>) = 9,
+}
+
+#[test]
+fn test_derive_try_from_str() {
+    assert_eq!(Exact::try_from("Alpha"), Ok(Exact::Alpha));
+    assert_eq!(Exact::try_from("alpha"), Err(Unrepresentable("alpha".to_owned())));
+    assert_eq!(Exact::try_from("b"), Ok(Exact::Beta));
+    assert_eq!(Exact::try_from("bravo"), Ok(Exact::Beta));
+    assert_eq!(Exact::try_from("Bravo"), Err(Unrepresentable("Bravo".to_owned())));
+    assert_eq!(Exact::try_from(String::from("Beta")), Ok(Exact::Beta));
+    assert_eq!(Exact::try_from(String::from("Gamma")), Err(Unrepresentable(String::from("Gamma"))));
+    let res: Result<Exact, _> = "Alpha".try_into();
+    assert_eq!(res, Ok(Exact::Alpha));
+
+    // The source borrow need not outlive the call.
+    let owned = String::from("b");
+    assert_eq!(Exact::try_from(&owned[..]), Ok(Exact::Beta));
+    drop(owned);
+
+    type L = Loose<()>;
+    assert_eq!(L::try_from("YES"), Ok(Loose::Yes));
+    assert_eq!(L::try_from("On"), Ok(Loose::Yes));
+    assert_eq!(L::try_from("off"), Ok(Loose::No));
+    assert_eq!(L::try_from("Other"), Err(Unrepresentable("Other".to_owned())));
+    assert_eq!(L::try_from(1u8), Ok(Loose::Yes));
+    assert_eq!(L::try_from(9u8), Err(Unrepresentable(9u8)));
+}
+
+#[test]
+fn test_derive_try_from_str_reverse() {
+    use conv::{ValueFrom, ValueInto};
+
+    assert_eq!(<&str>::value_from(Exact::Beta), Ok("Beta"));
+    let name: &'static str = Exact::Alpha.value_into().unwrap();
+    assert_eq!(name, "Alpha");
+    assert_eq!(<&str>::value_from(Loose::<()>::Other(PhantomData)), Err(Unrepresentable(Loose::Other(PhantomData))));
+
+    // Every name produced converts back to the same variant.
+    for name in &["Alpha", "Beta"] {
+        assert_eq!(Exact::try_from(*name).ok().and_then(|e| <&str>::value_from(e).ok()), Some(*name));
+    }
+    for v in &[Loose::<()>::Yes, Loose::No, Loose::Other(PhantomData)] {
+        if let Ok(name) = <&str>::value_from(v.clone()) {
+            assert_eq!(Loose::try_from(name).as_ref(), Ok(v));
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, TryFrom)]
+#[try_from(u16, i32, u8, &str)]
+#[repr(u16)]
+enum Status {
+    Ok = 200,
+    #[try_from(alias = "missing")]
+    NotFound = 404,
+    #[try_from(catch_all)]
+    Other(u16),
+}
+
+#[test]
+fn test_derive_try_from_catch_all() {
+    use conv::{NoError, ValueFrom};
+
+    let res: Result<Status, NoError> = Status::try_from(200u16);
+    assert_eq!(res, Ok(Status::Ok));
+    assert_eq!(Status::try_from(404u16), Ok(Status::NotFound));
+    assert_eq!(Status::try_from(418u16), Ok(Status::Other(418)));
+
+    // Other sources only fail if the value does not fit the catch-all field.
+    assert_eq!(Status::try_from(404i32), Ok(Status::NotFound));
+    assert_eq!(Status::try_from(503i32), Ok(Status::Other(503)));
+    assert_eq!(Status::try_from(-1i32), Err(Unrepresentable(-1i32)));
+    assert_eq!(Status::try_from(70_000i32), Err(Unrepresentable(70_000i32)));
+    assert_eq!(Status::try_from(7u8), Ok(Status::Other(7)));
+
+    assert_eq!(u16::value_from(Status::Ok), Ok(200));
+    assert_eq!(u16::value_from(Status::Other(418)), Ok(418));
+    for v in 0..1000u16 {
+        assert_eq!(Status::try_from(v).map(u16::value_from), Ok(Ok(v)));
+    }
+
+    assert_eq!(Status::try_from("missing"), Ok(Status::NotFound));
+    assert_eq!(Status::try_from("Other"), Err(Unrepresentable("Other".to_owned())));
+    assert_eq!(<&str>::value_from(Status::Other(1)), Err(Unrepresentable(Status::Other(1))));
+    for v in &[Status::Ok, Status::NotFound, Status::Other(200), Status::Other(1)] {
+        if let Ok(name) = <&str>::value_from(v.clone()) {
+            assert_eq!(Status::try_from(name).as_ref(), Ok(v));
+        }
+    }
+}
@@ -0,0 +1,78 @@
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use conv::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, ValueInto)]
+enum Implicit { A, B, C }
+
+#[derive(Copy, Clone, Debug, PartialEq, ValueInto)]
+#[repr(i16)]
+enum Signed { Low = -300, Mid = -1, High = 200 }
+
+#[derive(Copy, Clone, Debug, PartialEq, ValueInto)]
+#[repr(i8)]
+enum Negative { Min = -128, Max = -100 }
+
+const BASE: u64 = 1 << 40;
+
+#[derive(Copy, Clone, Debug, PartialEq, ValueInto)]
+#[repr(u64)]
+enum Opaque { First = BASE, Second }
+
+#[derive(Copy, Clone, Debug, PartialEq, TryFrom, ValueInto)]
+#[repr(u8)]
+enum RoundTrip { Zero, One, Ten = 10 }
+
+#[derive(Debug, PartialEq, ValueInto)]
+enum Never {}
+
+#[test]
+fn test_derive_value_into() {
+    let r: Result<isize, NoError> = Implicit::C.value_into();
+    assert_eq!(r, Ok(2));
+    let r: Result<u8, NoError> = Implicit::B.value_into();
+    assert_eq!(r, Ok(1));
+    let r: Result<i128, NoError> = Implicit::A.approx_into();
+    assert_eq!(r, Ok(0));
+
+    let r: Result<i16, NoError> = Signed::Low.value_into();
+    assert_eq!(r, Ok(-300));
+    let r: Result<i32, NoError> = Signed::Low.value_into();
+    assert_eq!(r, Ok(-300));
+    let r: Result<i8, RangeError<Signed>> = Signed::Low.value_into();
+    assert_eq!(r, Err(RangeError::NegOverflow(Signed::Low)));
+    let r: Result<i8, RangeError<Signed>> = Signed::High.value_into();
+    assert_eq!(r, Err(RangeError::PosOverflow(Signed::High)));
+    let r: Result<i8, RangeError<Signed>> = Signed::Mid.value_into();
+    assert_eq!(r, Ok(-1));
+    let r: Result<u8, NegOverflow<Signed>> = Signed::High.value_into();
+    assert_eq!(r, Ok(200));
+    let r: Result<u64, NegOverflow<Signed>> = Signed::Mid.value_into();
+    assert_eq!(r, Err(NegOverflow(Signed::Mid)));
+    assert_eq!(Signed::Mid.value_as::<u64>().map_err(GeneralErrorKind::from), Err(GeneralErrorKind::NegOverflow));
+
+    let r: Result<u128, NegOverflow<Negative>> = Negative::Max.value_into();
+    assert_eq!(r, Err(NegOverflow(Negative::Max)));
+    let r: Result<i8, NoError> = Negative::Min.value_into();
+    assert_eq!(r, Ok(-128));
+
+    // Non-literal discriminants fall back on the range of the representation.
+    let r: Result<u64, NoError> = Opaque::Second.value_into();
+    assert_eq!(r, Ok(BASE + 1));
+    let r: Result<u32, PosOverflow<Opaque>> = Opaque::First.value_into();
+    assert_eq!(r, Err(PosOverflow(Opaque::First)));
+    let r: Result<i64, PosOverflow<Opaque>> = Opaque::First.value_into();
+    assert_eq!(r, Ok(BASE as i64));
+
+    for v in 0..20u8 {
+        if let Ok(e) = RoundTrip::try_from(v) {
+            assert_eq!(e.value_as::<u8>(), Ok(v));
+        }
+    }
+    let r: Result<i8, NoError> = RoundTrip::Ten.value_into();
+    assert_eq!(r, Ok(10));
+
+    fn never(n: Never) -> Result<u8, NoError> { n.value_into() }
+    let _ = never;
+}
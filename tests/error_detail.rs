@@ -0,0 +1,56 @@
+extern crate conv;
+
+use std::num::NonZeroU8;
+use conv::*;
+
+fn detail<T, E>(r: Result<T, E>) -> String where Result<T, E>: WithDetail<i32, Output=Result<T, DetailedError>> {
+    match r.with_detail() {
+        Ok(_) => panic!("conversion unexpectedly succeeded"),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[test]
+fn test_detail_messages() {
+    assert_eq!(detail(u8::value_from(300i32)), "300i32 exceeds u8::MAX (255)");
+    assert_eq!(detail(u8::value_from(-1i32)), "-1i32 is below u8::MIN (0)");
+    assert_eq!(detail(i8::value_from(-129i32)), "-129i32 is below i8::MIN (-128)");
+    assert_eq!(detail(char::try_from(0xd800i32)), "55296i32 cannot be represented as char");
+    assert_eq!(detail(Ranged::<u8, 1, 10>::value_from(11i32)), "11i32 exceeds Ranged<u8, 1, 10>::MAX (10)");
+    assert_eq!(detail(bool::try_from(2i32)), "2i32 cannot be represented as bool");
+
+    // Integers only convert into floats while every value is exactly representable.
+    assert_eq!(detail(f32::value_from(16_777_217i32)), "16777217i32 exceeds the maximum for f32 (16777216)");
+    assert_eq!(detail(f32::value_from(-16_777_217i32)), "-16777217i32 is below the minimum for f32 (-16777216)");
+    assert_eq!(f64::value_from(u64::MAX).with_detail().unwrap_err().to_string(),
+        "18446744073709551615u64 exceeds the maximum for f64 (9007199254740992)");
+    assert_eq!(f32::value_from(1i64 << 40).with_detail().unwrap_err().bound(), Some("16777216"));
+
+    assert_eq!(u8::value_from(255i32).with_detail(), Ok(255));
+    assert_eq!(<u8 as ApproxFrom<_>>::approx_from(f64::NAN).with_detail().unwrap_err().to_string(),
+        "f64 value NaN cannot be represented as u8");
+    assert_eq!(<f32 as ApproxFrom<_>>::approx_from(1e300f64).with_detail().unwrap_err().to_string(),
+        "1e300f64 exceeds f32::MAX (3.4028235e38)");
+    assert_eq!(NonZeroU8::try_from(0u8).with_detail().unwrap_err().to_string(),
+        "0u8 cannot be represented as NonZero<u8>, which does not support zero");
+    assert_eq!(u8::value_from('λ').with_detail().unwrap_err().to_string(),
+        "char value λ exceeds u8::MAX (255)");
+}
+
+#[test]
+fn test_detail_fields() {
+    let e = u16::value_from(70000u32).with_detail().unwrap_err();
+    assert_eq!(e.kind(), GeneralErrorKind::PosOverflow);
+    assert_eq!(e.src_type(), "u32");
+    assert_eq!(e.dst_type(), "u16");
+    assert_eq!(e.value(), Some("70000"));
+    assert_eq!(e.bound(), Some("65535"));
+    assert_eq!(GeneralErrorKind::from(e), GeneralErrorKind::PosOverflow);
+
+    let e = DetailedError::without_value::<Vec<u8>, u8>(GeneralErrorKind::NegOverflow);
+    assert_eq!(e.value(), None);
+    assert_eq!(e.to_string(), "Vec<u8> value is below u8::MIN (0)");
+
+    let e = DetailedError::without_value::<i32, bool>(GeneralErrorKind::PosOverflow);
+    assert_eq!(e.to_string(), "i32 value exceeds the maximum of bool");
+}
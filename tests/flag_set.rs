@@ -0,0 +1,100 @@
+#[macro_use] extern crate conv;
+
+use conv::*;
+
+flag_set! {
+    /// Access permissions.
+    pub struct Perms: u8 {
+        const READ = 0b001;
+        const WRITE = 0b010;
+        /// Execute.
+        const EXEC = 0b100;
+        const READ_WRITE = 0b011;
+    }
+}
+
+flag_set! {
+    struct Mode: u8 {
+        const ON = 0b0001;
+        const COLOUR = 0b1100;
+    }
+}
+
+flag_set! {
+    pub(crate) struct Control: u32 {
+        const ENABLE = 1;
+        const RESET = 1 << 31;
+    }
+}
+
+mod nested {
+    flag_set! {
+        struct NoFlags: u16 {}
+    }
+
+    #[test]
+    fn test_empty_flag_set() {
+        use conv::{ApproxFrom, TryFrom, Unrepresentable, Wrapping};
+        assert_eq!(NoFlags::try_from(0), Ok(NoFlags::empty()));
+        assert_eq!(NoFlags::try_from(1), Err(Unrepresentable(1)));
+        assert_eq!(<NoFlags as ApproxFrom<u16, Wrapping>>::approx_from(0xffff), Ok(NoFlags::all()));
+    }
+}
+
+#[test]
+fn test_flag_set_try_from() {
+    assert_eq!(Perms::try_from(0), Ok(Perms::empty()));
+    assert_eq!(Perms::try_from(0b101), Ok(Perms::READ | Perms::EXEC));
+    assert_eq!(Perms::try_from(0b111), Ok(Perms::all()));
+    assert_eq!(Perms::try_from(0b1000), Err(Unrepresentable(0b1000)));
+    assert_eq!(Perms::try_from(0xff), Err(Unrepresentable(0xff)));
+    let res: Result<Perms, _> = 0b011u8.try_into();
+    assert_eq!(res, Ok(Perms::READ_WRITE));
+
+    assert_eq!(Control::try_from(0x8000_0001), Ok(Control::ENABLE | Control::RESET));
+    assert_eq!(Control::try_from(2), Err(Unrepresentable(2)));
+}
+
+#[test]
+fn test_flag_set_approx_wrapping() {
+    assert_eq!(<Perms as ApproxFrom<u8, Wrapping>>::approx_from(0xff), Ok(Perms::all()));
+    assert_eq!(0b1010u8.approx_by::<Wrapping>(), Ok(Perms::WRITE));
+    assert_eq!(0xffff_fffeu32.approx_by::<Wrapping>(), Ok(Control::RESET));
+}
+
+#[test]
+fn test_flag_set_value_into() {
+    assert_eq!(u8::value_from(Perms::READ | Perms::EXEC), Ok(0b101));
+    assert_eq!((Control::RESET).value_as::<u32>(), Ok(1 << 31));
+    for bits in 0..=0xffu8 {
+        if let Ok(p) = Perms::try_from(bits) {
+            assert_eq!(u8::value_from(p), Ok(bits));
+        }
+    }
+}
+
+#[test]
+fn test_flag_set_ops() {
+    let mut p = Perms::READ;
+    assert!(!p.is_empty());
+    assert!(p.contains(Perms::READ));
+    assert!(!p.contains(Perms::READ_WRITE));
+    p.insert(Perms::WRITE);
+    assert_eq!(p, Perms::READ_WRITE);
+    assert_eq!(p.bits(), 0b011);
+    p.remove(Perms::READ);
+    assert_eq!(p, Perms::WRITE);
+    assert_eq!(Perms::all() - Perms::WRITE, Perms::READ | Perms::EXEC);
+    assert_eq!(Perms::all() & Perms::READ_WRITE, Perms::READ_WRITE);
+    assert_eq!(Perms::READ ^ Perms::READ_WRITE, Perms::WRITE);
+    assert!(Perms::empty().is_empty());
+
+    assert_eq!(format!("{:?}", Perms::empty()), "Perms(empty)");
+    assert_eq!(format!("{:?}", Perms::EXEC), "Perms(EXEC)");
+    assert_eq!(format!("{:?}", Perms::all()), "Perms(READ | WRITE | EXEC)");
+    assert_eq!(format!("{:?}", Perms::READ_WRITE), "Perms(READ | WRITE)");
+
+    // Bits only covered by a composite flag are shown as a number.
+    assert_eq!(format!("{:?}", Mode::ON | Mode::COLOUR), "Mode(ON | 0xc)");
+    assert_eq!(format!("{:?}", Mode::COLOUR), "Mode(0xc)");
+}
@@ -0,0 +1,25 @@
+extern crate conv;
+
+use conv::*;
+
+#[test]
+fn test_bool_to_int() {
+    assert_eq!(false.value_as::<u8>().unwrap_ok(), 0);
+    assert_eq!(true.value_as::<u8>().unwrap_ok(), 1);
+    assert_eq!(true.value_as::<i8>().unwrap_ok(), 1);
+    assert_eq!(true.value_as::<i64>().unwrap_ok(), 1);
+    assert_eq!(true.value_as::<u128>().unwrap_ok(), 1);
+    assert_eq!(true.value_as::<usize>().unwrap_ok(), 1);
+}
+
+#[test]
+fn test_int_to_bool() {
+    assert_eq!(0u8.try_as::<bool>(), Ok(false));
+    assert_eq!(1u8.try_as::<bool>(), Ok(true));
+    assert_eq!(2u8.try_as::<bool>(), Err(Unrepresentable(2)));
+    assert_eq!(0i32.try_as::<bool>(), Ok(false));
+    assert_eq!(1i32.try_as::<bool>(), Ok(true));
+    assert_eq!((-1i32).try_as::<bool>(), Err(Unrepresentable(-1)));
+    assert_eq!(1isize.try_as::<bool>(), Ok(true));
+    assert_eq!(256u128.try_as::<bool>(), Err(Unrepresentable(256)));
+}
@@ -0,0 +1,52 @@
+extern crate conv;
+
+use conv::*;
+
+#[test]
+fn test_char_to_int() {
+    assert_eq!('A'.value_as::<u32>(), Ok(0x41));
+    assert_eq!('\u{10FFFF}'.value_as::<u32>(), Ok(0x10FFFF));
+    assert_eq!('\u{10FFFF}'.value_as::<i64>(), Ok(0x10FFFF));
+    assert_eq!('\u{10FFFF}'.value_as::<u128>().unwrap_ok(), 0x10FFFF);
+    assert_eq!('\u{FF}'.value_as::<u8>(), Ok(0xFF));
+    assert_eq!('\u{100}'.value_as::<u8>(), Err(PosOverflow('\u{100}')));
+    assert_eq!('\u{7F}'.value_as::<i8>(), Ok(0x7F));
+    assert_eq!('\u{80}'.value_as::<i8>(), Err(PosOverflow('\u{80}')));
+    assert_eq!('\u{FFFF}'.value_as::<u16>(), Ok(0xFFFF));
+    assert_eq!('\u{10000}'.value_as::<u16>(), Err(PosOverflow('\u{10000}')));
+    assert_eq!('\u{10000}'.approx_as::<i16>(), Err(PosOverflow('\u{10000}')));
+    assert_eq!('\u{1F600}'.approx_as_by::<u8, Wrapping>(), Ok(0x00));
+    assert_eq!('\u{1F641}'.approx_as_by::<u16, Wrapping>(), Ok(0xF641));
+}
+
+#[test]
+fn test_int_to_char() {
+    assert_eq!(0x41u8.value_as::<char>().unwrap_ok(), 'A');
+    assert_eq!(0xFFu8.try_as::<char>().unwrap_ok(), '\u{FF}');
+    assert_eq!(0x41u32.value_as::<char>(), Ok('A'));
+    assert_eq!(0x41u32.try_as::<char>(), Ok('A'));
+    assert_eq!(0xD7FFu16.value_as::<char>(), Ok('\u{D7FF}'));
+    assert_eq!(0xD800u16.value_as::<char>(), Err(Unrepresentable(0xD800)));
+    assert_eq!(0xDFFFu32.try_as::<char>(), Err(Unrepresentable(0xDFFF)));
+    assert_eq!(0xE000u32.try_as::<char>(), Ok('\u{E000}'));
+    assert_eq!(0x10FFFFu32.value_as::<char>(), Ok('\u{10FFFF}'));
+    assert_eq!(0x110000u32.value_as::<char>(), Err(Unrepresentable(0x110000)));
+    assert_eq!((-1i32).value_as::<char>(), Err(Unrepresentable(-1)));
+    assert_eq!(0x1_0000_0041u64.try_as::<char>(), Err(Unrepresentable(0x1_0000_0041)));
+    assert_eq!(0x41i128.try_as::<char>(), Ok('A'));
+}
+
+#[test]
+fn test_char_round_trip() {
+    extern crate quickcheck;
+
+    fn property(c: char) -> bool {
+        c.value_as::<u32>().unwrap_ok().value_as::<char>() == Ok(c)
+    }
+
+    let mut qc = quickcheck::QuickCheck::new();
+    match qc.quicktest(property as fn(char) -> bool) {
+        Ok(_) => (),
+        Err(err) => panic!("char round trip {:?}", err)
+    }
+}
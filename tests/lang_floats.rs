@@ -15,26 +15,140 @@ fn test_f32() {
 
 #[test]
 fn test_f32_to_int() {
-    check!(f32, i8=>  sidenta; qa: i8=>  a: -129.0, !FU; a: 128.0, !FO;);
-    check!(f32, i16=> sidenta; qa: i16=> a: -32_769.0, !FU; a: 32_768.0, !FO;);
-    check!(f32, i32=> sidenta; qa: i32=> a: -2_147_500_000.0, !FU; a: 2_147_500_000.0, !FO;);
-    check!(f32, i64=> sidenta; qa: i64=> a: -9_223_373_000_000_000_000.0, !FU; a: 9_223_373_000_000_000_000.0, !FO;);
-    check!(f32, u8=>  uidenta; qa: u8=>  a: -1.0, !FU; a: 256.0, !FO;);
-    check!(f32, u16=> uidenta; qa: u16=> a: -1.0, !FU; a: 65_536.0, !FO;);
-    check!(f32, u32=> uidenta; qa: u32=> a: -1.0, !FU; a: 4_294_968_000.0, !FO;);
-    check!(f32, u64=> uidenta; qa: u64=> a: -1.0, !FU; a: 18_446_746_000_000_000_000.0, !FO;);
+    check!(f32, i8=>  sidenta; qa: i8=>  qaRHE: i8=>  a: -129.0, !FU; a: 128.0, !FO;);
+    check!(f32, i16=> sidenta; qa: i16=> qaRHE: i16=> a: -32_769.0, !FU; a: 32_768.0, !FO;);
+    check!(f32, i32=> sidenta; qa: i32=> qaRHE: i32=>
+        aRHE: 2_147_483_648.0, !FO;
+        a: -2_147_500_000.0, !FU;
+        a: 2_147_500_000.0, !FO;
+    );
+    check!(f32, i64=> sidenta; qa: i64=> qaRHE: i64=>
+        aRHE: 9_223_372_036_854_775_808.0, !FO;
+        a: -9_223_373_000_000_000_000.0, !FU;
+        a: 9_223_373_000_000_000_000.0, !FO;
+    );
+    check!(f32, u8=>  uidenta; qa: u8=>  qaRHE: u8=>  a: -1.0, !FU; a: 256.0, !FO;);
+    check!(f32, u16=> uidenta; qa: u16=> qaRHE: u16=> a: -1.0, !FU; a: 65_536.0, !FO;);
+    check!(f32, u32=> uidenta; qa: u32=> qaRHE: u32=>
+        aRHE: 4_294_967_296.0, !FO;
+        a: -1.0, !FU;
+        a: 4_294_968_000.0, !FO;
+    );
+    check!(f32, u64=> uidenta; qa: u64=> qaRHE: u64=>
+        aRHE: 18_446_744_073_709_551_616.0, !FO;
+        a: -1.0, !FU;
+        a: 18_446_746_000_000_000_000.0, !FO;
+    );
+    check!(f32, i128=> sidenta; qa: i128=> qaRHE: i128=>
+        a: -170_141_210_000_000_000_000_000_000_000_000_000_000.0, !FU;
+        a: 170_141_210_000_000_000_000_000_000_000_000_000_000.0, !FO;
+    );
+    check!(f32, u128=> uidenta; qa: u128=> qaRHE: u128=> a: -1.0, !FU;);
 }
 
 #[test]
 fn test_f64_to_int() {
-    check!(f64, i8=>  sidenta; qa: i8=>  a: -129.0, !FU; a: 128.0, !FO;);
-    check!(f64, i16=> sidenta; qa: i16=> a: -32_769.0, !FU; a: 32_768.0, !FO;);
-    check!(f64, i32=> sidenta; qa: i32=> a: -2_147_483_649.0, !FU; a: 2_147_483_648.0, !FO;);
-    check!(f64, i64=> sidenta; qa: i64=> a: -9_223_372_036_854_778_000.0, !FU; a: 9_223_372_036_854_778_000.0, !FO;);
-    check!(f64, u8=>  uidenta; qa: u8=>  a: -1.0, !FU; a: 256.0, !FO;);
-    check!(f64, u16=> uidenta; qa: u16=> a: -1.0, !FU; a: 65_536.0, !FO;);
-    check!(f64, u32=> uidenta; qa: u32=> a: -1.0, !FU; a: 4_294_967_296.0, !FO;);
-    check!(f64, u64=> uidenta; qa: u64=> a: -1.0, !FU; a: 18_446_744_073_709_560_000.0, !FO;);
+    check!(f64, i8=>  sidenta; qa: i8=>  qaRHE: i8=>  a: -129.0, !FU; a: 128.0, !FO;);
+    check!(f64, i16=> sidenta; qa: i16=> qaRHE: i16=> a: -32_769.0, !FU; a: 32_768.0, !FO;);
+    check!(f64, i32=> sidenta; qa: i32=> qaRHE: i32=>
+        aRHE: 2_147_483_648.0, !FO;
+        a: -2_147_483_649.0, !FU;
+        a: 2_147_483_648.0, !FO;
+    );
+    check!(f64, i64=> sidenta; qa: i64=> qaRHE: i64=>
+        aRHE: 9_223_372_036_854_775_808.0, !FO;
+        a: -9_223_372_036_854_778_000.0, !FU;
+        a: 9_223_372_036_854_778_000.0, !FO;
+    );
+    check!(f64, u8=>  uidenta; qa: u8=>  qaRHE: u8=>  a: -1.0, !FU; a: 256.0, !FO;);
+    check!(f64, u16=> uidenta; qa: u16=> qaRHE: u16=> a: -1.0, !FU; a: 65_536.0, !FO;);
+    check!(f64, u32=> uidenta; qa: u32=> qaRHE: u32=>
+        aRHE: 4_294_967_296.0, !FO;
+        a: -1.0, !FU;
+        a: 4_294_967_296.0, !FO;
+    );
+    check!(f64, u64=> uidenta; qa: u64=> qaRHE: u64=>
+        aRHE: 18_446_744_073_709_551_616.0, !FO;
+        a: -1.0, !FU;
+        a: 18_446_744_073_709_560_000.0, !FO;
+    );
+    check!(f64, i128=> sidenta; qa: i128=> qaRHE: i128=>
+        a: -170_141_183_460_469_310_000_000_000_000_000_000_000.0, !FU;
+        a: 170_141_183_460_469_310_000_000_000_000_000_000_000.0, !FO;
+    );
+    check!(f64, u128=> uidenta; qa: u128=> qaRHE: u128=>
+        a: -1.0, !FU;
+        a: 340_282_366_920_938_620_000_000_000_000_000_000_000.0, !FO;
+    );
+}
+
+/// Checks the result of approximating `$v` as `$dst` with every rounding scheme.
+macro_rules! check_all_rounding {
+    ($v:expr, $dst:ty, $res:expr) => {
+        assert_eq!(<$dst as ApproxFrom<_, DefaultApprox>>::approx_from($v), $res);
+        assert_eq!(<$dst as ApproxFrom<_, RoundToNearest>>::approx_from($v), $res);
+        assert_eq!(<$dst as ApproxFrom<_, RoundHalfEven>>::approx_from($v), $res);
+        assert_eq!(<$dst as ApproxFrom<_, RoundToNegInf>>::approx_from($v), $res);
+        assert_eq!(<$dst as ApproxFrom<_, RoundToPosInf>>::approx_from($v), $res);
+        assert_eq!(<$dst as ApproxFrom<_, RoundToZero>>::approx_from($v), $res);
+    };
+}
+
+#[test]
+fn test_float_to_128_bounds() {
+    // `u128::MAX as f32` is infinite, and `i128::MAX as f32` is 2^127; neither may be accepted.
+    check_all_rounding!(f32::INFINITY, u128, Err(FO(f32::INFINITY)));
+    check_all_rounding!(f32::NEG_INFINITY, u128, Err(FU(f32::NEG_INFINITY)));
+    check_all_rounding!(f32::INFINITY, i128, Err(FO(f32::INFINITY)));
+    check_all_rounding!(f32::NEG_INFINITY, i128, Err(FU(f32::NEG_INFINITY)));
+    check_all_rounding!(f64::INFINITY, u128, Err(FO(f64::INFINITY)));
+    check_all_rounding!(f64::NEG_INFINITY, u128, Err(FU(f64::NEG_INFINITY)));
+    check_all_rounding!(f64::INFINITY, i128, Err(FO(f64::INFINITY)));
+    check_all_rounding!(f64::NEG_INFINITY, i128, Err(FU(f64::NEG_INFINITY)));
+
+    // The upper bounds are exclusive: 2^N itself overflows, the float just below it does not.
+    check_all_rounding!(f32::MAX, u128, Ok(u128::MAX - ((1 << 104) - 1)));
+    check_all_rounding!(2.0f64.powi(128), u128, Err(FO(2.0f64.powi(128))));
+    check_all_rounding!(2.0f64.powi(128) - 2.0f64.powi(75), u128, Ok(u128::MAX - ((1 << 75) - 1)));
+    check_all_rounding!(2.0f32.powi(127), i128, Err(FO(2.0f32.powi(127))));
+    check_all_rounding!(2.0f32.powi(127) - 2.0f32.powi(103), i128, Ok(i128::MAX - ((1 << 103) - 1)));
+    check_all_rounding!(2.0f64.powi(127), i128, Err(FO(2.0f64.powi(127))));
+    check_all_rounding!(2.0f64.powi(127) - 2.0f64.powi(74), i128, Ok(i128::MAX - ((1 << 74) - 1)));
+
+    // The lower bound of `i128` is exactly representable, and inclusive.
+    check_all_rounding!(-2.0f32.powi(127), i128, Ok(i128::MIN));
+    check_all_rounding!(-2.0f32.powi(127) - 2.0f32.powi(104), i128, Err(FU(-2.0f32.powi(127) - 2.0f32.powi(104))));
+    check_all_rounding!(-2.0f64.powi(127), i128, Ok(i128::MIN));
+    check_all_rounding!(-2.0f64.powi(127) - 2.0f64.powi(75), i128, Err(FU(-2.0f64.powi(127) - 2.0f64.powi(75))));
+}
+
+#[test]
+fn test_float_to_int_pow2_bounds() {
+    // The maximum of each of these rounds up to 2^N as a float, which must still overflow.
+    check_all_rounding!(2.0f32.powi(31), i32, Err(FO(2.0f32.powi(31))));
+    check_all_rounding!(2.0f32.powi(31) - 128.0, i32, Ok(i32::MAX - 127));
+    check_all_rounding!(2.0f32.powi(32), u32, Err(FO(2.0f32.powi(32))));
+    check_all_rounding!(2.0f32.powi(32) - 256.0, u32, Ok(u32::MAX - 255));
+    check_all_rounding!(2.0f32.powi(63), i64, Err(FO(2.0f32.powi(63))));
+    check_all_rounding!(2.0f32.powi(63) - 2.0f32.powi(39), i64, Ok(i64::MAX - ((1 << 39) - 1)));
+    check_all_rounding!(2.0f32.powi(64), u64, Err(FO(2.0f32.powi(64))));
+    check_all_rounding!(2.0f32.powi(64) - 2.0f32.powi(40), u64, Ok(u64::MAX - ((1 << 40) - 1)));
+    check_all_rounding!(2.0f64.powi(63), i64, Err(FO(2.0f64.powi(63))));
+    check_all_rounding!(2.0f64.powi(63) - 1024.0, i64, Ok(i64::MAX - 1023));
+    check_all_rounding!(2.0f64.powi(64), u64, Err(FO(2.0f64.powi(64))));
+    check_all_rounding!(2.0f64.powi(64) - 2048.0, u64, Ok(u64::MAX - 2047));
+
+    let isize_bits = 8 * ::std::mem::size_of::<isize>() as i32;
+    check_all_rounding!(2.0f32.powi(isize_bits - 1), isize, Err(FO(2.0f32.powi(isize_bits - 1))));
+    check_all_rounding!(2.0f32.powi(isize_bits), usize, Err(FO(2.0f32.powi(isize_bits))));
+    check_all_rounding!(2.0f64.powi(isize_bits - 1), isize, Err(FO(2.0f64.powi(isize_bits - 1))));
+    check_all_rounding!(2.0f64.powi(isize_bits), usize, Err(FO(2.0f64.powi(isize_bits))));
+
+    // The lower bounds are exactly representable, and inclusive.
+    check_all_rounding!(-2.0f32.powi(31), i32, Ok(i32::MIN));
+    check_all_rounding!(-2.0f32.powi(63), i64, Ok(i64::MIN));
+    check_all_rounding!(-2.0f64.powi(63), i64, Ok(i64::MIN));
+    check_all_rounding!(-2.0f64.powi(63) - 2048.0, i64, Err(FU(-2.0f64.powi(63) - 2048.0)));
 }
 
 #[test]
@@ -42,3 +156,122 @@ fn test_f64() {
     check!(f64, f32=> fidenta; qa: *;);
     check!(f64, f64=> fident; qv: *;);
 }
+
+#[test]
+fn test_f64_to_f32_rounding() {
+    use conv::RangeError::{NegOverflow as RU, PosOverflow as RO};
+
+    macro_rules! rnd {
+        ($src:expr, $scheme:ty) => { ($src).approx_as_by::<f32, $scheme>() };
+    }
+
+    let below_tenth = 0.099_999_994f32;
+    assert_eq!(rnd!(0.1f64, RoundToNegInf), Ok(below_tenth));
+    assert_eq!(rnd!(0.1f64, RoundToPosInf), Ok(0.1f32));
+    assert_eq!(rnd!(0.1f64, RoundToZero), Ok(below_tenth));
+    assert_eq!(rnd!(0.1f64, RoundToNearest), Ok(0.1f32));
+    assert_eq!(rnd!(-0.1f64, RoundToNegInf), Ok(-0.1f32));
+    assert_eq!(rnd!(-0.1f64, RoundToPosInf), Ok(-below_tenth));
+    assert_eq!(rnd!(-0.1f64, RoundToZero), Ok(-below_tenth));
+    assert_eq!(rnd!(-0.1f64, RoundToNearest), Ok(-0.1f32));
+
+    // Ties between 1 and the next f32 up; `as` would round these to even.
+    let tie = 1.0f64 + 2.0f64.powi(-24);
+    assert_eq!(rnd!(tie, RoundToNearest), Ok(1.000_000_1f32));
+    assert_eq!(rnd!(-tie, RoundToNearest), Ok(-1.000_000_1f32));
+    assert_eq!(rnd!(tie, RoundToZero), Ok(1.0f32));
+
+    assert_eq!(rnd!(1e-50f64, RoundToPosInf), Ok(f32::from_bits(1)));
+    assert_eq!(rnd!(1e-50f64, RoundToNegInf), Ok(0.0f32));
+    assert_eq!(rnd!(-1e-50f64, RoundToNegInf), Ok(-f32::from_bits(1)));
+
+    assert_eq!(rnd!(std::f32::MAX as f64, RoundToPosInf), Ok(std::f32::MAX));
+    assert_eq!(rnd!(1e39f64, RoundToNegInf), Err(RO(1e39)));
+    assert_eq!(rnd!(-1e39f64, RoundToPosInf), Err(RU(-1e39)));
+    assert_eq!(rnd!(std::f64::INFINITY, RoundToZero), Ok(std::f32::INFINITY));
+    assert!(rnd!(std::f64::NAN, RoundToNearest).unwrap().is_nan());
+
+    extern crate quickcheck;
+
+    fn property(v: f64) -> bool {
+        let lo = rnd!(v, RoundToNegInf).unwrap();
+        let hi = rnd!(v, RoundToPosInf).unwrap();
+        let near = rnd!(v, RoundToNearest).unwrap();
+        let exact = lo as f64 == v;
+        lo as f64 <= v && v <= hi as f64
+            && (exact == (lo == hi))
+            && (exact || (lo.to_bits() as i64 - hi.to_bits() as i64).abs() == 1)
+            && (near == lo || near == hi)
+    }
+
+    let mut qc = quickcheck::QuickCheck::new();
+    match qc.quicktest(property as fn(f64) -> bool) {
+        Ok(_) => (),
+        Err(err) => panic!("f64 -> f32 rounding {:?}", err)
+    }
+}
+
+#[test]
+fn test_saturating() {
+    assert_eq!((-23.0f32).approx_as_by::<u8, Saturating>(), Ok(0));
+    assert_eq!(302.0f32.approx_as_by::<u8, Saturating>(), Ok(255));
+    assert_eq!(41.7f32.approx_as_by::<u8, Saturating>(), Ok(41));
+    assert_eq!(41.7f32.approx_as_by::<u8, Saturating<RoundToNearest>>(), Ok(42));
+    assert_eq!(254.5f64.approx_as_by::<u8, Saturating<RoundHalfEven>>(), Ok(254));
+    assert_eq!(255.5f64.approx_as_by::<u8, Saturating<RoundToPosInf>>(), Ok(255));
+    assert_eq!((-0.5f64).approx_as_by::<i8, Saturating<RoundToNegInf>>(), Ok(-1));
+    assert_eq!(1e300f64.approx_as_by::<i64, Saturating>(), Ok(std::i64::MAX));
+    assert_eq!(std::f32::NEG_INFINITY.approx_as_by::<i32, Saturating>(), Ok(std::i32::MIN));
+    assert!(std::f32::NAN.approx_as_by::<u8, Saturating>().is_err());
+
+    // Generic code can choose to saturate through the scheme parameter.
+    fn to_u8<S: ApproxScheme, T: ApproxInto<u8, S>>(v: T) -> Result<u8, T::Err> {
+        v.approx_into()
+    }
+    assert_eq!(to_u8::<Saturating, _>(-7i32), Ok(0));
+    assert_eq!(to_u8::<Saturating, _>(1000.0f64), Ok(255));
+}
+
+#[test]
+fn test_wrapping() {
+    macro_rules! wrap {
+        ($src:expr, $dst:ty) => { ($src).approx_as_by::<$dst, Wrapping>() };
+    }
+
+    assert_eq!(wrap!(41.9f32, u8), Ok(41));
+    assert_eq!(wrap!(-41.9f32, i8), Ok(-41));
+    assert_eq!(wrap!(-1.5f64, u8), Ok(255));
+    assert_eq!(wrap!(300.7f64, u8), Ok(44));
+    assert_eq!(wrap!(0.5f32, u32), Ok(0));
+    assert_eq!(wrap!(1e-40f32, u32), Ok(0));
+    assert_eq!(wrap!(2.0f64.powi(70), u64), Ok(0));
+    assert_eq!(wrap!(3.0 * 2.0f64.powi(63), u64), Ok(1 << 63));
+    assert_eq!(wrap!(3.0 * 2.0f64.powi(63), i64), Ok(std::i64::MIN));
+    assert_eq!(wrap!(3.0 * 2.0f32.powi(100), u128), Ok(3 << 100));
+    assert_eq!(wrap!(2.0f32.powi(127), i128), Ok(std::i128::MIN));
+    assert_eq!(wrap!(-(2.0f64.powi(64) + 2.0f64.powi(12)), u64), Ok(std::u64::MAX - 4095));
+    assert_eq!(wrap!(1e300f64, i32), Ok(0));
+
+    assert_eq!(wrap!(std::f32::INFINITY, u8), Err(FO(std::f32::INFINITY)));
+    assert_eq!(wrap!(std::f64::NEG_INFINITY, i64), Err(FU(std::f64::NEG_INFINITY)));
+    assert!(wrap!(std::f64::NAN, u16).is_err());
+
+    extern crate quickcheck;
+
+    fn property(v: f64, scale: u8) -> bool {
+        let v = (v * 2.0f64.powi(scale as i32 % 62)).trunc();
+        if !(v.abs() < 2.0f64.powi(63)) {
+            return true;
+        }
+        let w = v as i64;
+        wrap!(v, u8) == Ok(w as u8) && wrap!(v, i16) == Ok(w as i16)
+            && wrap!(v, u32) == Ok(w as u32) && wrap!(v, u64) == Ok(w as u64)
+            && wrap!(v, i128) == Ok(w as i128)
+    }
+
+    let mut qc = quickcheck::QuickCheck::new();
+    match qc.quicktest(property as fn(f64, u8) -> bool) {
+        Ok(_) => (),
+        Err(err) => panic!("float wrapping {:?}", err)
+    }
+}
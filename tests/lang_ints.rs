@@ -15,6 +15,7 @@ fn test_i8() {
     check!(i8, i16=> sident; qv: *; qa: *; qaW: *);
     check!(i8, i32=> sident; qv: *; qa: *; qaW: *);
     check!(i8, i64=> sident; qv: *; qa: *; qaW: *);
+    check!(i8, i128=> sident; qv: *; qa: *; qaW: *);
     check!(i8, u8=> uident; qv: +; qa: +; qaW: *;
         v: -1, !Uf;
     );
@@ -27,6 +28,9 @@ fn test_i8() {
     check!(i8, u64=> uident; qv: +; qa: +; qaW: *;
         v: -1, !Uf;
     );
+    check!(i8, u128=> uident; qv: +; qa: +; qaW: *;
+        v: -1, !Uf;
+    );
     check!(i8, isize=> sident; qv: *; qa: *; qaW: *);
     check!(i8, usize=> uident; qv: +; qa: +; qaW: *;
         v: -1, !Uf;
@@ -41,6 +45,7 @@ fn test_i16() {
     check!(i16, i16=> sident; qv: *; qa: *; qaW: *);
     check!(i16, i32=> sident; qv: *; qa: *; qaW: *);
     check!(i16, i64=> sident; qv: *; qa: *; qaW: *);
+    check!(i16, i128=> sident; qv: *; qa: *; qaW: *);
     check!(i16, u8=> uident; qv: u8=> qa: +; qaW: *;
         v: -1, !RU;
     );
@@ -53,6 +58,9 @@ fn test_i16() {
     check!(i16, u64=> uident; qv: +; qa: +; qaW: *;
         v: -1, !Uf;
     );
+    check!(i16, u128=> uident; qv: +; qa: +; qaW: *;
+        v: -1, !Uf;
+    );
     check!(i16, isize=> sident; qv: *; qa: *; qaW: *);
     check!(i16, usize=> uident; qv: +; qa: +; qaW: *;
         v: -1, !Uf;
@@ -69,6 +77,7 @@ fn test_i32() {
     );
     check!(i32, i32=> sident; qv: *; qa: *; qaW: *);
     check!(i32, i64=> sident; qv: *; qa: *; qaW: *);
+    check!(i32, i128=> sident; qv: *; qa: *; qaW: *);
     check!(i32, u8=> uident; qv: u8=> qa: u8=> qaW: *;
         v: -1, !RU;
     );
@@ -81,6 +90,9 @@ fn test_i32() {
     check!(i32, u64=> uident; qv: +; qa: +; qaW: *;
         v: -1, !Uf;
     );
+    check!(i32, u128=> uident; qv: +; qa: +; qaW: *;
+        v: -1, !Uf;
+    );
     for_bitness! {
         32 {
             check!(i32, isize=> sident; qv: *; qa: *; qaW: *);
@@ -107,6 +119,7 @@ fn test_i64() {
     );
     check!(i64, i64=> sident; qv: *; qa: *; qaW: *;
     );
+    check!(i64, i128=> sident; qv: *; qa: *; qaW: *);
     check!(i64, u8=> uident; qv: u8=> qa: u8=> qaW: *;
         v: -1, !RU;
     );
@@ -119,6 +132,9 @@ fn test_i64() {
     check!(i64, u64=> uident; qv: +; qa: +; qaW: *;
         v: -1, !Uf;
     );
+    check!(i64, u128=> uident; qv: +; qa: +; qaW: *;
+        v: -1, !Uf;
+    );
     for_bitness! {
         32 {
             check!(i64, isize=> sident; qv: isize=> qa: isize=> qaW: *;
@@ -142,10 +158,12 @@ fn test_u8() {
     check!(u8, i16=> uident; qv: *; qa: *; qaW: *);
     check!(u8, i32=> uident; qv: *; qa: *; qaW: *);
     check!(u8, i64=> uident; qv: *; qa: *; qaW: *);
+    check!(u8, i128=> uident; qv: *; qa: *; qaW: *);
     check!(u8, u8=> uident; qv: *; qa: *; qaW: *);
     check!(u8, u16=> uident; qv: *; qa: *; qaW: *);
     check!(u8, u32=> uident; qv: *; qa: *; qaW: *);
     check!(u8, u64=> uident; qv: *; qa: *; qaW: *);
+    check!(u8, u128=> uident; qv: *; qa: *; qaW: *);
     check!(u8, isize=> uident; qv: *; qa: *; qaW: *);
     check!(u8, usize=> uident; qv: *; qa: *; qaW: *);
 }
@@ -160,12 +178,14 @@ fn test_u16() {
     );
     check!(u16, i32=> uident; qv: *; qa: *; qaW: *);
     check!(u16, i64=> uident; qv: *; qa: *; qaW: *);
+    check!(u16, i128=> uident; qv: *; qa: *; qaW: *);
     check!(u16, u8=> uident; qv: u8=> qa: u8=> qaW: *;
         v: 256, !Of;
     );
     check!(u16, u16=> uident; qv: *; qa: *; qaW: *);
     check!(u16, u32=> uident; qv: *; qa: *; qaW: *);
     check!(u16, u64=> uident; qv: *; qa: *; qaW: *);
+    check!(u16, u128=> uident; qv: *; qa: *; qaW: *);
     check!(u16, isize=> uident; qv: *; qa: *; qaW: *);
     check!(u16, usize=> uident; qv: *; qa: *; qaW: *);
 }
@@ -182,6 +202,7 @@ fn test_u32() {
         v: 2_147_483_648, !Of;
     );
     check!(u32, i64=> uident; qv: *; qa: *; qaW: *);
+    check!(u32, i128=> uident; qv: *; qa: *; qaW: *);
     check!(u32, u8=> uident; qv: u8=> qa: u8=> qaW: *;
         v: 256, !Of;
     );
@@ -190,6 +211,7 @@ fn test_u32() {
     );
     check!(u32, u32=> uident; qv: *; qa: *; qaW: *);
     check!(u32, u64=> uident; qv: *; qa: *; qaW: *);
+    check!(u32, u128=> uident; qv: *; qa: *; qaW: *);
     for_bitness! {
         32 {
             check!(u32, isize=> uident; qv: +isize=> qa: +isize=> qaW: *;
@@ -217,6 +239,7 @@ fn test_u64() {
     check!(u64, i64=> uident; qv: +i64=> qa: +i64=> qaW: *;
         v: 9_223_372_036_854_775_808, !Of;
     );
+    check!(u64, i128=> uident; qv: *; qa: *; qaW: *);
     check!(u64, u8=> uident; qv: u8=> qa: u8=> qaW: *;
         v: 256, !Of;
     );
@@ -227,6 +250,7 @@ fn test_u64() {
         v: 4_294_967_296, !Of;
     );
     check!(u64, u64=> uident; qv: *; qa: *; qaW: *);
+    check!(u64, u128=> uident; qv: *; qa: *; qaW: *);
     for_bitness! {
         32 {
             check!(u64, isize=> uident; qv: +isize=> qa: +isize=> qaW: *;
@@ -257,6 +281,10 @@ fn test_isize() {
         v: -1, !RU; v: 65_536, !RO;
     );
     check!(isize, isize=> sident; qv: *; qa: *; qaW: *);
+    check!(isize, i128=> sident; qv: *; qa: *; qaW: *);
+    check!(isize, u128=> uident; qv: +; qa: +; qaW: *;
+        v: -1, !Uf;
+    );
     for_bitness! {
         32 {
             check!(isize, i32=> sident; qv: *; qa: *; qaW: *);
@@ -292,6 +320,8 @@ fn test_usize() {
         v: 65_536, !Of;
     );
     check!(usize, usize=> uident; qv: *; qa: *; qaW: *);
+    check!(usize, i128=> uident; qv: *; qa: *; qaW: *);
+    check!(usize, u128=> uident; qv: *; qa: *; qaW: *);
     for_bitness! {
         32 {
             check!(usize, i32=> uident; qv: +i32=> qa: +i32=> qaW: *);
@@ -306,6 +336,76 @@ fn test_usize() {
     }
 }
 
+#[test]
+fn test_i128() {
+    check!(i128, i8=> sident; qv: i8=> qa: i8=> qaW: *;
+        v: -129, !RU; v: 128, !RO;
+    );
+    check!(i128, i16=> sident; qv: i16=> qa: i16=> qaW: *;
+        v: -32_769, !RU; v: 32_768, !RO;
+    );
+    check!(i128, i32=> sident; qv: i32=> qa: i32=> qaW: *;
+        v: -2_147_483_649, !RU; v: 2_147_483_648, !RO;
+    );
+    check!(i128, i64=> sident; qv: i64=> qa: i64=> qaW: *;
+        v: -9_223_372_036_854_775_809, !RU; v: 9_223_372_036_854_775_808, !RO;
+    );
+    check!(i128, i128=> sident; qv: *; qa: *; qaW: *);
+    check!(i128, u8=> uident; qv: u8=> qa: u8=> qaW: *;
+        v: -1, !RU; v: 256, !RO;
+    );
+    check!(i128, u16=> uident; qv: u16=> qa: u16=> qaW: *;
+        v: -1, !RU; v: 65_536, !RO;
+    );
+    check!(i128, u32=> uident; qv: u32=> qa: u32=> qaW: *;
+        v: -1, !RU; v: 4_294_967_296, !RO;
+    );
+    check!(i128, u64=> uident; qv: u64=> qa: u64=> qaW: *;
+        v: -1, !RU; v: 18_446_744_073_709_551_616, !RO;
+    );
+    check!(i128, u128=> uident; qv: +; qa: +; qaW: *;
+        v: -1, !Uf;
+    );
+    check!(i128, isize=> sident; qv: isize=> qa: isize=> qaW: *);
+    check!(i128, usize=> uident; qv: usize=> qa: usize=> qaW: *;
+        v: -1, !RU;
+    );
+}
+
+#[test]
+fn test_u128() {
+    check!(u128, i8=> uident; qv: +i8=> qa: +i8=> qaW: *;
+        v: 128, !Of;
+    );
+    check!(u128, i16=> uident; qv: +i16=> qa: +i16=> qaW: *;
+        v: 32_768, !Of;
+    );
+    check!(u128, i32=> uident; qv: +i32=> qa: +i32=> qaW: *;
+        v: 2_147_483_648, !Of;
+    );
+    check!(u128, i64=> uident; qv: +i64=> qa: +i64=> qaW: *;
+        v: 9_223_372_036_854_775_808, !Of;
+    );
+    check!(u128, i128=> uident; qv: +i128=> qa: +i128=> qaW: *;
+        v: 170_141_183_460_469_231_731_687_303_715_884_105_728, !Of;
+    );
+    check!(u128, u8=> uident; qv: u8=> qa: u8=> qaW: *;
+        v: 256, !Of;
+    );
+    check!(u128, u16=> uident; qv: u16=> qa: u16=> qaW: *;
+        v: 65_536, !Of;
+    );
+    check!(u128, u32=> uident; qv: u32=> qa: u32=> qaW: *;
+        v: 4_294_967_296, !Of;
+    );
+    check!(u128, u64=> uident; qv: u64=> qa: u64=> qaW: *;
+        v: 18_446_744_073_709_551_616, !Of;
+    );
+    check!(u128, u128=> uident; qv: *; qa: *; qaW: *);
+    check!(u128, isize=> uident; qv: +isize=> qa: +isize=> qaW: *);
+    check!(u128, usize=> uident; qv: usize=> qa: usize=> qaW: *);
+}
+
 #[test]
 fn test_i_to_f() {
     check!(i8,  f32=> sident; qv: *; qa: *);
@@ -325,6 +425,19 @@ fn test_i_to_f() {
     check!(u64, f32=> uident; qv: (, 16_777_216); qa: *;
         v: 16_777_217, !Of;
     );
+    check!(i128, f32=> sident; qv: (+-16_777_216); qa: *;
+        v: -16_777_217, !RU; v: 16_777_217, !RO;
+    );
+    check!(isize, f32=> sident; qv: (+-16_777_216); qa: *;
+        v: -16_777_217, !RU; v: 16_777_217, !RO;
+    );
+    check!(u128, f32=> uident; qv: (, 16_777_216); qa: *;
+        v: 16_777_217, !Of;
+        a: 340_282_366_920_938_463_463_374_607_431_768_211_455, !Of;
+    );
+    check!(usize, f32=> uident; qv: (, 16_777_216); qa: *;
+        v: 16_777_217, !Of;
+    );
 
     check!(i8,  f64=> sident; qv: *; qa: *);
     check!(i16, f64=> sident; qv: *; qa: *);
@@ -339,4 +452,134 @@ fn test_i_to_f() {
     check!(u64, f64=> uident; qv: (, 9_007_199_254_740_992); qa: *;
         v: 9_007_199_254_740_993, !Of;
     );
+    check!(i128, f64=> sident; qv: (+-9_007_199_254_740_992); qa: *;
+        v: -9_007_199_254_740_993, !RU; v: 9_007_199_254_740_993, !RO;
+    );
+    check!(u128, f64=> uident; qv: (, 9_007_199_254_740_992); qa: *;
+        v: 9_007_199_254_740_993, !Of;
+    );
+}
+
+#[test]
+fn test_i_to_f_rounding() {
+    macro_rules! rnd {
+        ($src:expr, $dst:ty, $scheme:ident) => { ($src).approx_as_by::<$dst, $scheme>() };
+    }
+
+    assert_eq!(rnd!(3i8, f32, RoundToNearest), Ok(3.0));
+    assert_eq!(rnd!(-3i8, f64, RoundToNegInf), Ok(-3.0));
+
+    assert_eq!(rnd!(16_777_217i32, f32, RoundToNearest), Ok(16_777_218.0));
+    assert_eq!(rnd!(16_777_217i32, f32, RoundHalfEven), Ok(16_777_216.0));
+    assert_eq!(rnd!(16_777_217i32, f32, RoundToZero), Ok(16_777_216.0));
+    assert_eq!(rnd!(16_777_217i32, f32, RoundToNegInf), Ok(16_777_216.0));
+    assert_eq!(rnd!(16_777_217i32, f32, RoundToPosInf), Ok(16_777_218.0));
+    assert_eq!(rnd!(16_777_219i32, f32, RoundHalfEven), Ok(16_777_220.0));
+
+    assert_eq!(rnd!(-16_777_217i64, f32, RoundToNearest), Ok(-16_777_218.0));
+    assert_eq!(rnd!(-16_777_217i64, f32, RoundHalfEven), Ok(-16_777_216.0));
+    assert_eq!(rnd!(-16_777_217i64, f32, RoundToZero), Ok(-16_777_216.0));
+    assert_eq!(rnd!(-16_777_217i64, f32, RoundToNegInf), Ok(-16_777_218.0));
+    assert_eq!(rnd!(-16_777_217i64, f32, RoundToPosInf), Ok(-16_777_216.0));
+
+    assert_eq!(rnd!(9_007_199_254_740_993u64, f64, RoundToNearest), Ok(9_007_199_254_740_994.0));
+    assert_eq!(rnd!(9_007_199_254_740_993u64, f64, RoundHalfEven), Ok(9_007_199_254_740_992.0));
+    assert_eq!(rnd!(std::u64::MAX, f64, RoundToZero), Ok(18_446_744_073_709_549_568.0));
+    assert_eq!(rnd!(std::u64::MAX, f64, RoundToPosInf), Ok(18_446_744_073_709_551_616.0));
+    assert_eq!(rnd!(std::i64::MIN, f32, RoundToNegInf), Ok(-9_223_372_036_854_775_808.0));
+
+    assert_eq!(rnd!(std::u128::MAX, f32, RoundToZero), Ok(std::f32::MAX));
+    assert_eq!(rnd!(std::u128::MAX, f32, RoundToNearest), Err(Of(std::u128::MAX)));
+    assert_eq!(rnd!(std::u128::MAX, f32, RoundToPosInf), Err(Of(std::u128::MAX)));
+
+    macro_rules! qrnd {
+        ($src:ident, $dst:ident) => {
+            {
+                extern crate quickcheck;
+
+                fn property(v: $src, shift: u8) -> bool {
+                    let v = v.wrapping_shl(shift as u32);
+                    let lo = rnd!(v, $dst, RoundToNegInf).unwrap();
+                    let hi = rnd!(v, $dst, RoundToPosInf).unwrap();
+                    let zero = rnd!(v, $dst, RoundToZero).unwrap();
+                    let near = rnd!(v, $dst, RoundToNearest).unwrap();
+                    let even = rnd!(v, $dst, RoundHalfEven).unwrap();
+
+                    let wide = v as i128;
+                    let (dlo, dhi) = (wide - lo as i128, hi as i128 - wide);
+                    if dlo == 0 {
+                        return lo == hi && lo == zero && lo == near && lo == even;
+                    }
+                    let away = if v < 0 as $src { lo } else { hi };
+                    let odd = |f: $dst| f.to_bits() & 1 == 1;
+                    dlo > 0 && dhi > 0
+                        && (lo.to_bits() as i128 - hi.to_bits() as i128).abs() == 1
+                        && zero == (if v < 0 as $src { hi } else { lo })
+                        && near == (if dlo < dhi { lo } else if dhi < dlo { hi } else { away })
+                        && even == (if dlo < dhi { lo } else if dhi < dlo { hi }
+                            else if odd(lo) { hi } else { lo })
+                }
+
+                let mut qc = quickcheck::QuickCheck::new();
+                match qc.quicktest(property as fn($src, u8) -> bool) {
+                    Ok(_) => (),
+                    Err(err) => panic!("qrnd {:?}", err)
+                }
+            }
+        };
+    }
+
+    qrnd!(i32, f32);
+    qrnd!(i64, f32);
+    qrnd!(u64, f32);
+    qrnd!(i64, f64);
+    qrnd!(u64, f64);
+}
+
+#[test]
+fn test_saturating() {
+    macro_rules! sat {
+        ($src:expr, $dst:ty) => { ($src).approx_as_by::<$dst, Saturating>().unwrap_ok() };
+    }
+
+    assert_eq!(sat!(-1i8, u8), 0);
+    assert_eq!(sat!(300i16, u8), 255);
+    assert_eq!(sat!(-300i16, i8), -128);
+    assert_eq!(sat!(200u8, i8), 127);
+    assert_eq!(sat!(std::u64::MAX, i64), std::i64::MAX);
+    assert_eq!(sat!(std::i128::MIN, u128), 0);
+    assert_eq!(sat!(std::u128::MAX, isize), std::isize::MAX);
+    assert_eq!(sat!(42i64, u16), 42);
+    assert_eq!((-5i32).approx_as_by::<u32, Saturating<RoundToNearest>>(), Ok(0));
+
+    macro_rules! qsat {
+        ($src:ident, $dst:ident) => {
+            {
+                extern crate quickcheck;
+
+                fn property(v: $src, shift: u8) -> bool {
+                    let v = v.wrapping_shl(shift as u32);
+                    let (min, max) = (<$dst>::min_value() as i128, <$dst>::max_value() as i128);
+                    let clamped = if (v as i128) < min { min }
+                        else if (v as i128) > max { max }
+                        else { v as i128 };
+                    sat!(v, $dst) as i128 == clamped
+                }
+
+                let mut qc = quickcheck::QuickCheck::new();
+                match qc.quicktest(property as fn($src, u8) -> bool) {
+                    Ok(_) => (),
+                    Err(err) => panic!("qsat {:?}", err)
+                }
+            }
+        };
+    }
+
+    qsat!(i64, i8);
+    qsat!(i64, u8);
+    qsat!(i64, u32);
+    qsat!(i64, u64);
+    qsat!(u64, i16);
+    qsat!(u64, i64);
+    qsat!(i32, usize);
 }
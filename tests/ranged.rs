@@ -0,0 +1,68 @@
+extern crate conv;
+
+use conv::*;
+use conv::misc::Saturated;
+
+type Percent = Ranged<u8, 0, 100>;
+type Offset = Ranged<i16, -500, 500>;
+type Huge = Ranged<u128, 10, { i128::MAX }>;
+
+#[test]
+fn test_ranged_from_ints() {
+    assert_eq!(Percent::value_from(0i8).map(Ranged::get), Ok(0));
+    assert_eq!(Percent::value_from(100u64).map(Ranged::get), Ok(100));
+    assert_eq!(Percent::value_from(-1i8), Err(RangeError::NegOverflow(-1)));
+    assert_eq!(Percent::value_from(101usize), Err(RangeError::PosOverflow(101)));
+    assert_eq!(Percent::try_from(i128::MIN), Err(RangeError::NegOverflow(i128::MIN)));
+    assert_eq!(Percent::try_from(50u16).map(Ranged::get), Ok(50));
+
+    assert_eq!(Offset::value_from(-500i64).map(Ranged::get), Ok(-500));
+    assert_eq!(Offset::value_from(-501i32), Err(RangeError::NegOverflow(-501)));
+    assert_eq!(Offset::value_from(501u16), Err(RangeError::PosOverflow(501)));
+    assert_eq!(Offset::value_from(u128::MAX), Err(RangeError::PosOverflow(u128::MAX)));
+
+    assert_eq!(Huge::value_from(9u8), Err(RangeError::NegOverflow(9)));
+    assert_eq!(Huge::value_from(i128::MAX as u128).map(Ranged::get), Ok(i128::MAX as u128));
+    assert_eq!(Huge::value_from(i128::MAX as u128 + 1), Err(RangeError::PosOverflow(i128::MAX as u128 + 1)));
+    assert_eq!(Huge::value_from(u128::MAX), Err(RangeError::PosOverflow(u128::MAX)));
+
+    assert_eq!(GeneralErrorKind::from(Percent::value_from(-3i32).unwrap_err()), GeneralErrorKind::NegOverflow);
+}
+
+#[test]
+fn test_ranged_into_ints() {
+    let p = Percent::new(42).unwrap();
+    assert_eq!(u8::value_from(p), Ok(42));
+    assert_eq!(i64::value_from(p), Ok(42));
+    assert_eq!(p.value_as::<i8>(), Ok(42));
+
+    let o = Offset::new(-7).unwrap();
+    assert_eq!(i32::value_from(o), Ok(-7));
+    assert_eq!(u32::value_from(o), Err(NegOverflow(-7)));
+    assert_eq!(i8::value_from(Offset::new(300).unwrap()), Err(RangeError::PosOverflow(300)));
+}
+
+#[test]
+fn test_ranged_saturate() {
+    assert_eq!(Percent::saturated_min().get(), 0);
+    assert_eq!(Percent::saturated_max().get(), 100);
+    assert_eq!(Offset::min_value().get(), -500);
+    assert_eq!(Offset::max_value().get(), 500);
+
+    assert_eq!(300i32.value_as::<Percent>().unwrap_or_saturate().get(), 100);
+    assert_eq!((-300i32).value_as::<Percent>().unwrap_or_saturate().get(), 0);
+    assert_eq!(i64::MIN.value_as::<Offset>().unwrap_or_saturate().get(), -500);
+
+    let saturated: Vec<u8> = vec![-5i32, 50, 500].into_iter()
+        .saturating_as::<Percent>().map(Ranged::get).collect();
+    assert_eq!(saturated, vec![0, 50, 100]);
+}
+
+#[test]
+fn test_ranged_new() {
+    assert_eq!(Percent::new(100).map(Ranged::get), Some(100));
+    assert_eq!(Percent::new(101), None);
+    assert_eq!(Offset::new(-501), None);
+    assert_eq!(Percent::new(7).unwrap().to_string(), "7");
+    assert!(Percent::new(1) < Percent::new(2));
+}
@@ -0,0 +1,99 @@
+extern crate conv;
+
+use conv::*;
+
+#[test]
+fn test_vec() {
+    let readings: Vec<i64> = vec![12, -40_000, 7, 70_000];
+
+    let ok: Result<Vec<i32>, _> = readings.clone().value_into_each();
+    assert_eq!(ok, Ok(vec![12, -40_000, 7, 70_000]));
+
+    let res: Result<Vec<i16>, _> = readings.clone().value_into_each();
+    let err = res.unwrap_err();
+    assert_eq!(err.index, 1);
+    assert_eq!(err.error, RangeError::NegOverflow(-40_000));
+
+    let res: Result<Vec<i16>, _> = readings.value_into_each_all();
+    let errs = res.unwrap_err();
+    assert_eq!(errs.indices(), vec![1, 3]);
+    assert_eq!(errs.0[0].error, RangeError::NegOverflow(-40_000));
+    assert_eq!(errs.0[1].error, RangeError::PosOverflow(70_000));
+
+    let empty: Vec<u8> = vec![];
+    assert_eq!(empty.value_into_each_all(), Ok(Vec::<i8>::new()));
+}
+
+#[test]
+fn test_array() {
+    let arr: Result<[u8; 3], _> = [1i32, 2, 3].value_into_each();
+    assert_eq!(arr, Ok([1, 2, 3]));
+
+    let arr: Result<[u8; 3], _> = [1i32, 256, -1].value_into_each();
+    let err = arr.unwrap_err();
+    assert_eq!(err.index, 1);
+    assert_eq!(err.error, RangeError::PosOverflow(256));
+
+    let arr: Result<[u8; 3], _> = [1i32, 256, -1].value_into_each_all();
+    assert_eq!(arr.unwrap_err().indices(), vec![1, 2]);
+}
+
+#[test]
+fn test_boxed_slice() {
+    let boxed: Box<[f64]> = vec![1.0, 1.0e300, -1.0e300].into_boxed_slice();
+    let res: Result<Box<[f32]>, _> = boxed.clone().approx_each();
+    let err = res.unwrap_err();
+    assert_eq!(err.index, 1);
+    assert_eq!(err.error, RangeError::PosOverflow(1.0e300));
+
+    let res: Result<Box<[f32]>, _> = boxed.approx_each_all();
+    let errs = res.unwrap_err();
+    assert_eq!(errs.indices(), vec![1, 2]);
+    assert_eq!(errs.0[1].error, RangeError::NegOverflow(-1.0e300));
+
+    let boxed: Box<[f32]> = vec![1.0, f32::NAN, 1.0e10].into_boxed_slice();
+    let res: Result<Box<[i32]>, _> = boxed.approx_each_all();
+    let errs = res.unwrap_err();
+    assert_eq!(errs.indices(), vec![1, 2]);
+    match errs.0[0].error {
+        FloatError::NotANumber(v) => assert!(v.is_nan()),
+        ref e => panic!("unexpected error {:?}", e),
+    }
+    assert_eq!(errs.0[1].error, FloatError::PosOverflow(1.0e10));
+
+    let ok: Result<Box<[u64]>, _> = vec![1u8, 2].into_boxed_slice().value_into_each();
+    assert_eq!(&*ok.unwrap(), &[1, 2]);
+}
+
+#[test]
+fn test_slice() {
+    let src = [100u16, 200, 300];
+    let res: Result<Vec<u8>, _> = src[..2].value_into_each();
+    assert_eq!(res, Ok(vec![100, 200]));
+
+    let res: Result<Vec<u8>, _> = src[..].value_into_each();
+    assert_eq!(res.unwrap_err().error, PosOverflow(300));
+    assert_eq!(src, [100, 200, 300]);
+}
+
+#[test]
+fn test_display() {
+    let res: Result<Vec<u8>, _> = vec![1i32, -1].value_into_each();
+    assert_eq!(res.unwrap_err().to_string(),
+        "conversion of element 1 failed: conversion resulted in negative overflow");
+
+    let res: Result<Vec<u8>, _> = vec![-1i32, -1].value_into_each_all();
+    assert_eq!(res.unwrap_err().to_string(), "conversion of 2 element(s) failed");
+}
+
+#[test]
+fn test_approx_scheme() {
+    let res: Result<Vec<i32>, _> = vec![0.6f32, -1.6].approx_each_by::<RoundToNearest>();
+    assert_eq!(res, Ok(vec![1, -2]));
+
+    let res: Result<Vec<u8>, _> = [255.4f32, 255.5, 300.0][..].approx_each_all_by::<RoundToNearest>();
+    assert_eq!(res.unwrap_err().indices(), vec![1, 2]);
+
+    let res: Result<[u8; 2], _> = [1.0f32, -1.0].approx_each();
+    assert_eq!(res.unwrap_err().error, FloatError::NegOverflow(-1.0));
+}
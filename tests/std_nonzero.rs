@@ -0,0 +1,40 @@
+extern crate conv;
+
+use conv::*;
+use std::num::{NonZeroI8, NonZeroI32, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize};
+
+macro_rules! nz {
+    ($ty:ident($v:expr)) => { $ty::new($v).unwrap() };
+}
+
+#[test]
+fn test_nonzero_to_nonzero() {
+    assert_eq!(nz!(NonZeroU8(200)).value_as::<NonZeroU32>().unwrap_ok(), nz!(NonZeroU32(200)));
+    assert_eq!(nz!(NonZeroU32(200)).value_as::<NonZeroU8>(), Ok(nz!(NonZeroU8(200))));
+    assert_eq!(nz!(NonZeroU32(300)).value_as::<NonZeroU8>(), Err(PosOverflow(300)));
+    assert_eq!(nz!(NonZeroI8(-1)).value_as::<NonZeroU16>(), Err(NegOverflow(-1)));
+    assert_eq!(nz!(NonZeroI32(-1)).value_as::<NonZeroU8>(), Err(RangeError::NegOverflow(-1)));
+    assert_eq!(nz!(NonZeroI32(256)).value_as::<NonZeroU8>(), Err(RangeError::PosOverflow(256)));
+    assert_eq!(nz!(NonZeroU64(7)).value_as::<NonZeroUsize>(), Ok(nz!(NonZeroUsize(7))));
+}
+
+#[test]
+fn test_nonzero_and_prim() {
+    assert_eq!(nz!(NonZeroU32(42)).value_as::<u32>().unwrap_ok(), 42);
+    assert_eq!(nz!(NonZeroI8(-42)).value_as::<i8>().unwrap_ok(), -42);
+
+    assert_eq!(42u32.value_as::<NonZeroU32>(), Ok(nz!(NonZeroU32(42))));
+    assert_eq!(42u32.try_as::<NonZeroU32>(), Ok(nz!(NonZeroU32(42))));
+    assert_eq!(0u32.try_as::<NonZeroU32>(), Err(ZeroError(0)));
+    assert_eq!(0i8.value_as::<NonZeroI8>(), Err(ZeroError(0)));
+
+    // Widening, then rejecting zero, composes through `GeneralError`.
+    fn id_from(v: u64) -> Result<NonZeroU32, GeneralErrorKind> {
+        let v: u32 = v.value_into()?;
+        Ok(v.try_into()?)
+    }
+    assert_eq!(id_from(7), Ok(nz!(NonZeroU32(7))));
+    assert_eq!(id_from(0), Err(GeneralErrorKind::Zero));
+    assert_eq!(id_from(1 << 40), Err(GeneralErrorKind::PosOverflow));
+    assert_eq!(GeneralError::from(ZeroError(0u8)), GeneralError::Zero(0u8));
+}
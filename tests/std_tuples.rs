@@ -0,0 +1,44 @@
+extern crate conv;
+
+use conv::*;
+
+#[test]
+fn test_value_into_fields() {
+    let ok: Result<(i32, i32, f64), _> = (1i64, -2i64, 0.5f32).value_into_fields();
+    assert_eq!(ok, Ok((1, -2, 0.5)));
+
+    let res: Result<(i32, i32, f64), _> = (1i64, -(1i64 << 40), 0.5f32).value_into_fields();
+    let err = res.unwrap_err();
+    assert_eq!(err, FieldError::Field1(RangeError::NegOverflow(-(1 << 40))));
+    assert_eq!(err.field(), 1);
+    assert_eq!(err.to_string(), "conversion of field 1 failed: conversion resulted in negative overflow");
+    assert_eq!(GeneralErrorKind::from(err), GeneralErrorKind::NegOverflow);
+
+    // The first failing field is reported.
+    let res: Result<(u8, u8), _> = (-1i32, 256i32).value_into_fields();
+    assert_eq!(res, Err(FieldError::Field0(RangeError::NegOverflow(-1))));
+
+    let res: Result<(u8,), FieldError<RangeError<i32>>> = (7i32,).value_into_fields();
+    assert_eq!(res, Ok((7,)));
+
+    type Twelve = (u8, u16, u32, u64, i8, i16, i32, i64, f32, f64, u8, char);
+    let res: Result<Twelve, _> =
+        (0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u16, 0xd800u32).value_into_fields();
+    let err = res.unwrap_err();
+    assert_eq!(err.field(), 11);
+    assert_eq!(GeneralErrorKind::from(err), GeneralErrorKind::Unrepresentable);
+}
+
+#[test]
+fn test_approx_into_fields() {
+    let res: Result<(i32, u8, f32), _> = (1.5f64, 2.5f32, 0.25f64).approx_fields();
+    assert_eq!(res, Ok((1, 2, 0.25)));
+
+    let res: Result<(i32, u8), _> = (1.5f64, 2.5f32).approx_fields_by::<RoundToNearest>();
+    assert_eq!(res, Ok((2, 3)));
+
+    let res: Result<(i32, u8), _> = (1.5f64, -2.5f32).approx_fields_by::<RoundToNearest>();
+    let err = res.unwrap_err();
+    assert_eq!(err, FieldError::Field1(FloatError::NegOverflow(-2.5)));
+    assert_eq!(GeneralErrorKind::from(err), GeneralErrorKind::NegOverflow);
+}
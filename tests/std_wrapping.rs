@@ -0,0 +1,30 @@
+extern crate conv;
+
+use conv::*;
+use std::num::{Saturating as Sat, Wrapping as Wrap};
+
+#[test]
+fn test_wrapping() {
+    assert_eq!(Wrap(300u16).value_as::<Wrap<u8>>().unwrap_ok(), Wrap(44u8));
+    assert_eq!(Wrap(-1i32).value_as::<Wrap<u64>>().unwrap_ok(), Wrap(std::u64::MAX));
+    assert_eq!(Wrap(200u8).value_as::<Wrap<i8>>().unwrap_ok(), Wrap(-56i8));
+    assert_eq!(Wrap(7u8).value_as::<Wrap<u128>>().unwrap_ok(), Wrap(7u128));
+    assert_eq!(Wrap(300u16).approx_as::<Wrap<u8>>().unwrap_ok(), Wrap(44u8));
+    assert_eq!(Wrap(300u16).approx_as_by::<Wrap<u8>, RoundToNearest>().unwrap_ok(), Wrap(44u8));
+
+    assert_eq!(42u32.value_as::<Wrap<u32>>().unwrap_ok(), Wrap(42u32));
+    assert_eq!(Wrap(42u32).value_as::<u32>().unwrap_ok(), 42u32);
+}
+
+#[test]
+fn test_saturating() {
+    assert_eq!(Sat(300u16).value_as::<Sat<u8>>().unwrap_ok(), Sat(255u8));
+    assert_eq!(Sat(-1i32).value_as::<Sat<u64>>().unwrap_ok(), Sat(0u64));
+    assert_eq!(Sat(200u8).value_as::<Sat<i8>>().unwrap_ok(), Sat(127i8));
+    assert_eq!(Sat(-200i16).value_as::<Sat<i8>>().unwrap_ok(), Sat(-128i8));
+    assert_eq!(Sat(100i16).value_as::<Sat<i8>>().unwrap_ok(), Sat(100i8));
+    assert_eq!(Sat(300u16).approx_as_by::<Sat<u8>, Wrapping>().unwrap_ok(), Sat(255u8));
+
+    assert_eq!(42i64.value_as::<Sat<i64>>().unwrap_ok(), Sat(42i64));
+    assert_eq!(Sat(42i64).value_as::<i64>().unwrap_ok(), 42i64);
+}
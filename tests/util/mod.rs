@@ -1,3 +1,45 @@
+extern crate quickcheck;
+
+use std::fmt::Debug;
+use self::quickcheck::Arbitrary;
+
+/**
+Maps values generated by quickcheck onto a conversion source type.
+
+quickcheck does not implement `Arbitrary` for the 128-bit integers, so those are produced by shifting a narrower value across the full width of the type.
+*/
+pub trait Sample: Sized {
+    type Arb: Arbitrary + Debug;
+    fn sample(arb: Self::Arb) -> Self;
+}
+
+macro_rules! sample_as_self {
+    ($($ty:ty),*) => {
+        $(
+            impl Sample for $ty {
+                type Arb = $ty;
+                fn sample(arb: $ty) -> $ty { arb }
+            }
+        )*
+    };
+}
+
+sample_as_self! { i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64 }
+
+impl Sample for i128 {
+    type Arb = (i64, u8);
+    fn sample((v, shift): (i64, u8)) -> i128 {
+        (v as i128).wrapping_shl(shift as u32)
+    }
+}
+
+impl Sample for u128 {
+    type Arb = (u64, u8);
+    fn sample((v, shift): (u64, u8)) -> u128 {
+        (v as u128).wrapping_shl(shift as u32)
+    }
+}
+
 macro_rules! as_expr {
     ($e:expr) => {$e};
 }
@@ -53,6 +95,14 @@ macro_rules! check {
         check!(@ $from, $to=> aRTZ:  0.75,  0;);
         check!(@ $from, $to=> aRTZ:  1.00,  1;);
 
+        check!(@ $from, $to=> aRHE:  0.00,  0;);
+        check!(@ $from, $to=> aRHE:  0.25,  0;);
+        check!(@ $from, $to=> aRHE:  0.50,  0;);
+        check!(@ $from, $to=> aRHE:  0.75,  1;);
+        check!(@ $from, $to=> aRHE:  1.00,  1;);
+        check!(@ $from, $to=> aRHE:  1.50,  2;);
+        check!(@ $from, $to=> aRHE:  2.50,  2;);
+
         check!(@ $from, $to=> $($tail)*);
     };
 
@@ -101,6 +151,20 @@ macro_rules! check {
         check!(@ $from, $to=> aRTZ:  0.75,  0;);
         check!(@ $from, $to=> aRTZ:  1.00,  1;);
 
+        check!(@ $from, $to=> aRHE: -2.50, -2;);
+        check!(@ $from, $to=> aRHE: -1.50, -2;);
+        check!(@ $from, $to=> aRHE: -1.00, -1;);
+        check!(@ $from, $to=> aRHE: -0.75, -1;);
+        check!(@ $from, $to=> aRHE: -0.50,  0;);
+        check!(@ $from, $to=> aRHE: -0.25,  0;);
+        check!(@ $from, $to=> aRHE:  0.00,  0;);
+        check!(@ $from, $to=> aRHE:  0.25,  0;);
+        check!(@ $from, $to=> aRHE:  0.50,  0;);
+        check!(@ $from, $to=> aRHE:  0.75,  1;);
+        check!(@ $from, $to=> aRHE:  1.00,  1;);
+        check!(@ $from, $to=> aRHE:  1.50,  2;);
+        check!(@ $from, $to=> aRHE:  2.50,  2;);
+
         check!(@ $from, $to=> $($tail)*);
     };
 
@@ -133,13 +197,14 @@ macro_rules! check {
         {
             extern crate quickcheck;
 
-            fn property(v: $from) -> bool {
+            fn property(v: <$from as ::util::Sample>::Arb) -> bool {
+                let v = <$from as ::util::Sample>::sample(v);
                 let dst: Result<$to, _> = v.value_into();
                 dst == Ok(v as $to)
             }
 
             let mut qc = quickcheck::QuickCheck::new();
-            match qc.quicktest(property as fn($from) -> bool) {
+            match qc.quicktest(property as fn(<$from as ::util::Sample>::Arb) -> bool) {
                 Ok(_) => (),
                 Err(err) => panic!("qv {:?}", err)
             }
@@ -151,7 +216,8 @@ macro_rules! check {
         {
             extern crate quickcheck;
 
-            fn property(v: $from) -> bool {
+            fn property(v: <$from as ::util::Sample>::Arb) -> bool {
+                let v = <$from as ::util::Sample>::sample(v);
                 let dst: Result<$to, conv::FloatError<_>> = v.value_into().map_err(From::from);
                 if !(-$bound as $from <= v) {
                     dst == Err(conv::FloatError::NegOverflow(v))
@@ -163,7 +229,7 @@ macro_rules! check {
             }
 
             let mut qc = quickcheck::QuickCheck::new();
-            match qc.quicktest(property as fn($from) -> bool) {
+            match qc.quicktest(property as fn(<$from as ::util::Sample>::Arb) -> bool) {
                 Ok(_) => (),
                 Err(err) => panic!("qv {:?}", err)
             }
@@ -175,7 +241,8 @@ macro_rules! check {
         {
             extern crate quickcheck;
 
-            fn property(v: $from) -> bool {
+            fn property(v: <$from as ::util::Sample>::Arb) -> bool {
+                let v = <$from as ::util::Sample>::sample(v);
                 let dst: Result<$to, conv::FloatError<_>> = v.value_into().map_err(From::from);
                 if !(v <= $bound as $from) {
                     dst == Err(conv::FloatError::PosOverflow(v))
@@ -185,7 +252,7 @@ macro_rules! check {
             }
 
             let mut qc = quickcheck::QuickCheck::new();
-            match qc.quicktest(property as fn($from) -> bool) {
+            match qc.quicktest(property as fn(<$from as ::util::Sample>::Arb) -> bool) {
                 Ok(_) => (),
                 Err(err) => panic!("qv {:?}", err)
             }
@@ -197,7 +264,8 @@ macro_rules! check {
         {
             extern crate quickcheck;
 
-            fn property(v: $from) -> bool {
+            fn property(v: <$from as ::util::Sample>::Arb) -> bool {
+                let v = <$from as ::util::Sample>::sample(v);
                 let dst: Result<$to, conv::FloatError<_>> = v.value_into().map_err(From::from);
                 if !(0 <= v) {
                     dst == Err(conv::FloatError::NegOverflow(v))
@@ -207,7 +275,7 @@ macro_rules! check {
             }
 
             let mut qc = quickcheck::QuickCheck::new();
-            match qc.quicktest(property as fn($from) -> bool) {
+            match qc.quicktest(property as fn(<$from as ::util::Sample>::Arb) -> bool) {
                 Ok(_) => (),
                 Err(err) => panic!("qv {:?}", err)
             }
@@ -219,7 +287,8 @@ macro_rules! check {
         {
             extern crate quickcheck;
 
-            fn property(v: $from) -> bool {
+            fn property(v: <$from as ::util::Sample>::Arb) -> bool {
+                let v = <$from as ::util::Sample>::sample(v);
                 let dst: Result<$to, conv::FloatError<_>> = v.value_into().map_err(From::from);
                 if !(v <= <$max>::max_value() as $from) {
                     dst == Err(conv::FloatError::PosOverflow(v))
@@ -229,7 +298,7 @@ macro_rules! check {
             }
 
             let mut qc = quickcheck::QuickCheck::new();
-            match qc.quicktest(property as fn($from) -> bool) {
+            match qc.quicktest(property as fn(<$from as ::util::Sample>::Arb) -> bool) {
                 Ok(_) => (),
                 Err(err) => panic!("qv {:?}", err)
             }
@@ -241,7 +310,8 @@ macro_rules! check {
         {
             extern crate quickcheck;
 
-            fn property(v: $from) -> bool {
+            fn property(v: <$from as ::util::Sample>::Arb) -> bool {
+                let v = <$from as ::util::Sample>::sample(v);
                 let dst: Result<$to, conv::FloatError<_>> = v.value_into().map_err(From::from);
                 if !(<$bound>::min_value() as $from <= v) {
                     dst == Err(conv::FloatError::NegOverflow(v))
@@ -253,7 +323,7 @@ macro_rules! check {
             }
 
             let mut qc = quickcheck::QuickCheck::new();
-            match qc.quicktest(property as fn($from) -> bool) {
+            match qc.quicktest(property as fn(<$from as ::util::Sample>::Arb) -> bool) {
                 Ok(_) => (),
                 Err(err) => panic!("qv {:?}", err)
             }
@@ -265,7 +335,8 @@ macro_rules! check {
         {
             extern crate quickcheck;
 
-            fn property(v: $from) -> bool {
+            fn property(v: <$from as ::util::Sample>::Arb) -> bool {
+                let v = <$from as ::util::Sample>::sample(v);
                 let dst: Result<$to, conv::FloatError<_>> = v.value_into().map_err(From::from);
                 if !(<$min>::min_value() as $from <= v) {
                     dst == Err(conv::FloatError::NegOverflow(v))
@@ -277,7 +348,7 @@ macro_rules! check {
             }
 
             let mut qc = quickcheck::QuickCheck::new();
-            match qc.quicktest(property as fn($from) -> bool) {
+            match qc.quicktest(property as fn(<$from as ::util::Sample>::Arb) -> bool) {
                 Ok(_) => (),
                 Err(err) => panic!("qv {:?}", err)
             }
@@ -307,13 +378,14 @@ macro_rules! check {
         {
             extern crate quickcheck;
 
-            fn property(v: $from) -> bool {
+            fn property(v: <$from as ::util::Sample>::Arb) -> bool {
+                let v = <$from as ::util::Sample>::sample(v);
                 let dst: Result<$to, _> = v.approx_as();
                 dst == Ok(v as $to)
             }
 
             let mut qc = quickcheck::QuickCheck::new();
-            match qc.quicktest(property as fn($from) -> bool) {
+            match qc.quicktest(property as fn(<$from as ::util::Sample>::Arb) -> bool) {
                 Ok(_) => (),
                 Err(err) => panic!("qa {:?}", err)
             }
@@ -325,7 +397,8 @@ macro_rules! check {
         {
             extern crate quickcheck;
 
-            fn property(v: $from) -> bool {
+            fn property(v: <$from as ::util::Sample>::Arb) -> bool {
+                let v = <$from as ::util::Sample>::sample(v);
                 let dst: Result<$to, conv::FloatError<_>> = v.approx_as().map_err(From::from);
                 if !(0 <= v) {
                     dst == Err(conv::FloatError::NegOverflow(v))
@@ -335,7 +408,7 @@ macro_rules! check {
             }
 
             let mut qc = quickcheck::QuickCheck::new();
-            match qc.quicktest(property as fn($from) -> bool) {
+            match qc.quicktest(property as fn(<$from as ::util::Sample>::Arb) -> bool) {
                 Ok(_) => (),
                 Err(err) => panic!("qa {:?}", err)
             }
@@ -347,7 +420,8 @@ macro_rules! check {
         {
             extern crate quickcheck;
 
-            fn property(v: $from) -> bool {
+            fn property(v: <$from as ::util::Sample>::Arb) -> bool {
+                let v = <$from as ::util::Sample>::sample(v);
                 let dst: Result<$to, conv::FloatError<_>> = v.approx_as().map_err(From::from);
                 if !(v <= <$max>::max_value() as $from) {
                     dst == Err(conv::FloatError::PosOverflow(v))
@@ -357,7 +431,7 @@ macro_rules! check {
             }
 
             let mut qc = quickcheck::QuickCheck::new();
-            match qc.quicktest(property as fn($from) -> bool) {
+            match qc.quicktest(property as fn(<$from as ::util::Sample>::Arb) -> bool) {
                 Ok(_) => (),
                 Err(err) => panic!("qa {:?}", err)
             }
@@ -369,7 +443,8 @@ macro_rules! check {
         {
             extern crate quickcheck;
 
-            fn property(v: $from) -> bool {
+            fn property(v: <$from as ::util::Sample>::Arb) -> bool {
+                let v = <$from as ::util::Sample>::sample(v);
                 let dst: Result<$to, conv::FloatError<_>> = v.approx_as().map_err(From::from);
                 if !(<$bound>::min_value() as $from <= v) {
                     dst == Err(conv::FloatError::NegOverflow(v))
@@ -381,7 +456,7 @@ macro_rules! check {
             }
 
             let mut qc = quickcheck::QuickCheck::new();
-            match qc.quicktest(property as fn($from) -> bool) {
+            match qc.quicktest(property as fn(<$from as ::util::Sample>::Arb) -> bool) {
                 Ok(_) => (),
                 Err(err) => panic!("qa {:?}", err)
             }
@@ -393,13 +468,14 @@ macro_rules! check {
         {
             extern crate quickcheck;
 
-            fn property(v: $from) -> bool {
+            fn property(v: <$from as ::util::Sample>::Arb) -> bool {
+                let v = <$from as ::util::Sample>::sample(v);
                 let dst: Result<$to, _> = v.approx_as_by::<_, Wrapping>();
                 dst == Ok(v as $to)
             }
 
             let mut qc = quickcheck::QuickCheck::new();
-            match qc.quicktest(property as fn($from) -> bool) {
+            match qc.quicktest(property as fn(<$from as ::util::Sample>::Arb) -> bool) {
                 Ok(_) => (),
                 Err(err) => panic!("qaW {:?}", err)
             }
@@ -407,6 +483,60 @@ macro_rules! check {
         check!(@ $from, $to=> $($tail)*);
     };
 
+    (@ $from:ty, $to:ty=> qaRHE: $bound:ty=> $($tail:tt)*) => {
+        {
+            extern crate quickcheck;
+
+            fn property(v: <$from as ::util::Sample>::Arb) -> bool {
+                let v = <$from as ::util::Sample>::sample(v);
+                let dst: Result<$to, _> = v.approx_as_by::<_, conv::RoundHalfEven>();
+                if v.is_nan() {
+                    return dst == Err(conv::FloatError::NotANumber(v));
+                }
+                let floor = v.floor();
+                let rounded = match v - floor {
+                    d if d < 0.5 => floor,
+                    d if d > 0.5 => floor + 1.0,
+                    _ if floor % 2.0 == 0.0 => floor,
+                    _ => floor + 1.0,
+                };
+                // `MAX as $from` may round up to 2^N, so compare exclusively against `MAX + 1`.
+                if rounded < <$bound>::min_value() as $from {
+                    dst == Err(conv::FloatError::NegOverflow(v))
+                } else if rounded >= (<$bound>::max_value() / 2 + 1) as $from * 2.0 {
+                    dst == Err(conv::FloatError::PosOverflow(v))
+                } else {
+                    dst == Ok(rounded as $to)
+                }
+            }
+
+            let mut qc = quickcheck::QuickCheck::new();
+            match qc.quicktest(property as fn(<$from as ::util::Sample>::Arb) -> bool) {
+                Ok(_) => (),
+                Err(err) => panic!("qaRHE {:?}", err)
+            }
+        }
+        check!(@ $from, $to=> $($tail)*);
+    };
+
+    (@ $from:ty, $to:ty=> aRHE: $src:expr, !$dst:expr; $($tail:tt)*) => {
+        {
+            let src: $from = $src;
+            let dst: Result<$to, _> = src.approx_by::<conv::RoundHalfEven>();
+            assert_eq!(dst, Err($dst(src)));
+        }
+        check!(@ $from, $to=> $($tail)*);
+    };
+
+    (@ $from:ty, $to:ty=> aRHE: $src:expr, $dst:expr; $($tail:tt)*) => {
+        {
+            let src: $from = $src;
+            let dst: Result<$to, _> = src.approx_by::<conv::RoundHalfEven>();
+            assert_eq!(dst, Ok($dst));
+        }
+        check!(@ $from, $to=> $($tail)*);
+    };
+
     (@ $from:ty, $to:ty=> aRTN: $src:expr, $dst:expr; $($tail:tt)*) => {
         {
             let src: $from = $src;